@@ -0,0 +1,63 @@
+use crate::constants::parsing_constants::COMMENT_PREFIX;
+use std::fmt;
+use std::io::BufRead;
+
+/// Outcome of a batch, failures being given by line number.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BatchSummary {
+    pub solved: usize,
+    pub failed: Vec<usize>,
+}
+
+impl BatchSummary {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl fmt::Display for BatchSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} solved, {} failed", self.solved, self.failed.len())?;
+        if !self.is_success() {
+            let lines: Vec<String> = self.failed.iter().map(usize::to_string).collect();
+            let label: &str = if lines.len() == 1 { "line" } else { "lines" };
+            write!(f, " ({} {})", label, lines.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Hand each equation of `reader` to `process` with its line number, `process` parsing,
+/// printing and telling whether it was solved. Blank lines and lines starting with `#`
+/// are skipped, and a failure does not stop the batch. Invalid UTF-8 is replaced so that
+/// the parser reports it, and reading stops at the first I/O error, counted as a failure
+/// of the line it happened on.
+pub fn run_batch<R: BufRead>(
+    reader: R,
+    mut process: impl FnMut(usize, &str) -> bool,
+) -> BatchSummary {
+    let mut summary: BatchSummary = BatchSummary::default();
+
+    for (index, line) in reader.split(b'\n').enumerate() {
+        let number: usize = index + 1;
+        let line: Vec<u8> = match line {
+            Ok(line) => line,
+            Err(_) => {
+                summary.failed.push(number);
+                break;
+            }
+        };
+        let line: String = String::from_utf8_lossy(&line).into_owned();
+        let equation: &str = line.trim();
+        if equation.is_empty() || equation.starts_with(COMMENT_PREFIX) {
+            continue;
+        }
+
+        if process(number, equation) {
+            summary.solved += 1;
+        } else {
+            summary.failed.push(number);
+        }
+    }
+    summary
+}
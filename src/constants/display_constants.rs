@@ -0,0 +1,16 @@
+/// HTML report
+pub const HTML_HEAD: &str = "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>computorv1</title>
+<style>
+body { font-family: sans-serif; max-width: 48em; margin: 2em auto; line-height: 1.5; }
+section { border-top: 1px solid #ccc; padding: 0.5em 0; }
+math[display=\"block\"] { margin: 0.5em 0; }
+.error { color: #b00020; }
+</style>
+</head>
+<body>";
+pub const HTML_TAIL: &str = "</body>
+</html>";
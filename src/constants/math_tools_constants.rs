@@ -1,3 +1,17 @@
-/// Square root
-pub const TOLERANCE: f64 = 1e-20;
-pub const FRACT_PRECISION: i64 = 1_000;
+/// Series and iterative methods
+pub const SERIES_TOLERANCE: f64 = 1e-17;
+pub const MAX_ITERATIONS: usize = 10_000;
+pub const PI: f64 = std::f64::consts::PI;
+/// Numerical root finding
+pub const ROOT_FINDING_MAX_ITERATIONS: usize = 1_000;
+pub const POLISHING_STEPS: usize = 5;
+pub const NO_CONVERGENCE: &str = "the numerical method did not converge after ";
+/// Solver registry
+pub const NO_SOLVER: &str = "no registered solver handles degree ";
+/// Surd
+pub const SQUARE_FACTOR_LIMIT: u64 = 10_000;
+/// Verification
+pub const VERIFICATION_TOLERANCE: f64 = 1e-9;
+/// Factorization
+pub const FACTORING_DIVISOR_LIMIT: u64 = 1_000_000_000;
+pub const FACTORING_CANDIDATE_LIMIT: usize = 100_000;
@@ -1,2 +1,4 @@
+pub mod display_constants;
 pub mod math_tools_constants;
 pub mod parsing_constants;
+pub mod repl_constants;
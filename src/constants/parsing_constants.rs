@@ -1,7 +1,35 @@
 /// Parsing error
 pub const INVALID_ARG_NUMBER: &str = "wrong number of argument";
-pub const OPERATORS: &str = "+-";
+pub const INVALID_OPTION: &str = "unknown option: ";
+pub const NATURAL_FLAGS: [&str; 2] = ["-n", "--natural"];
+pub const NUMERICAL_FLAGS: [&str; 1] = ["--numerical"];
+pub const VERIFY_FLAGS: [&str; 1] = ["--verify"];
+pub const STURM_FLAGS: [&str; 1] = ["--sturm"];
+pub const FILE_FLAGS: [&str; 2] = ["-f", "--file"];
+pub const STDIN_PATH: &str = "-";
+pub const FORMAT_FLAGS: [&str; 1] = ["--format"];
+pub const TEXT_FORMAT: &str = "text";
+pub const JSON_FORMAT: &str = "json";
+pub const LATEX_FORMAT: &str = "latex";
+pub const HTML_FORMAT: &str = "html";
+pub const INVALID_FORMAT: &str = "unknown format: ";
+pub const COMMENT_PREFIX: char = '#';
+pub const MISSING_VALUE: &str = "missing value after ";
+pub const UNREADABLE_FILE: &str = "cannot read ";
+pub const DERIVE_COMMAND: &str = "derive";
+pub const INTEGRATE_COMMAND: &str = "integrate";
+pub const SUPERSCRIPT_DIGITS: &str = "⁰¹²³⁴⁵⁶⁷⁸⁹";
+pub const VARIABLES: &str = "Xx";
 pub const EMPTY_INPUT: &str = "please enter an equation";
 pub const INVALID_POWER: &str = "invalid power: ";
 pub const INVALID_COEFFICIENT: &str = "invalid coefficient: ";
-pub const PRECISION: u32 = 10;
+pub const UNEXPECTED_CHARACTER: &str = "unexpected character: ";
+pub const UNEXPECTED_TOKEN: &str = "unexpected token: ";
+pub const UNEXPECTED_END: &str = "unexpected end of equation";
+pub const UNCLOSED_PARENTHESIS: &str = "unclosed parenthesis";
+pub const DIVISION_BY_ZERO: &str = "division by zero: ";
+pub const NON_CONSTANT_DIVISOR: &str = "division by a non-constant expression: ";
+pub const DEGREE_TOO_HIGH: &str = "degree too high: ";
+/// Highest power and degree accepted by the parser.
+pub const MAX_DEGREE: usize = 1_000;
+pub const EXAMPLE: &str = "5 * X^0 + 4 * X^1 - 9.3 * X^2 = 1 * X^0";
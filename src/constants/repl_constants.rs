@@ -0,0 +1,23 @@
+/// Prompt and messages
+pub const PROMPT: &str = "> ";
+pub const WELCOME: &str = "computorv1 interactive mode, :help for the list of commands.";
+pub const HELP: &str = "Commands:
+  <equation>               solve an equation, as in X^2 - 1 = 0
+  <name> = <equation>      solve an equation and name it, as in p1 = X^2 - 1 = 0
+  <name>                   show a named equation again
+  derive <name|equation>   derivative of the reduced form
+  integrate <name|equation> antiderivative of the reduced form
+  verify <name|equation>   substitute the solutions into the reduced form
+  sturm <name|equation>    count and isolate the real roots
+  :history                 list the lines entered so far
+  :help                    show this message
+  :quit                    leave the interactive mode";
+/// Commands
+pub const HELP_COMMAND: &str = ":help";
+pub const HISTORY_COMMAND: &str = ":history";
+pub const QUIT_COMMAND: &str = ":quit";
+pub const VERIFY_COMMAND: &str = "verify";
+pub const STURM_COMMAND: &str = "sturm";
+/// Errors
+pub const UNKNOWN_COMMAND: &str = "unknown command: ";
+pub const UNKNOWN_EQUATION: &str = "unknown equation: ";
@@ -0,0 +1,26 @@
+use crate::errors::{ComputorError, Span};
+
+/// Print `error` followed, when it has a location, by the equation with the
+/// offending part underlined and a hint about how to fix it.
+pub fn display_error(equation: &str, error: &ComputorError) {
+    println!("Error: {}.", error);
+
+    if let Some(span) = error.span() {
+        println!("  {}", equation);
+        println!("  {}", underline(equation, span));
+    }
+    if let Some(hint) = error.hint() {
+        println!("Hint: {}", hint);
+    }
+}
+
+/// Private part
+fn underline(equation: &str, span: Span) -> String {
+    let start: usize = span.start.min(equation.len());
+    let end: usize = span.end.clamp(start, equation.len());
+
+    let padding: usize = equation[..start].chars().count();
+    let width: usize = equation[start..end].chars().count().max(1);
+
+    format!("{}{}", " ".repeat(padding), "^".repeat(width))
+}
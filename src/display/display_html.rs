@@ -0,0 +1,372 @@
+use crate::constants::display_constants::{HTML_HEAD, HTML_TAIL};
+use crate::errors::ComputorError;
+use crate::math_tools::polynomial::Polynomial;
+use crate::math_tools::rational::Rational;
+use crate::math_tools::root::Root;
+use crate::math_tools::solution::{Solution, SolutionSet};
+use crate::solvers::derivation::Step;
+use crate::solvers::formula::{Formula, Position, Relation, Sign};
+
+/// Standalone page for one solved equation, rendered by the browser without any script.
+pub fn polynomial_to_html(equation: &str, polynomial: &Polynomial) -> String {
+    html_document(&[polynomial_section(equation, polynomial)])
+}
+
+pub fn html_document(sections: &[String]) -> String {
+    format!("{}\n{}\n{}", HTML_HEAD, sections.join("\n"), HTML_TAIL)
+}
+
+/// Reduced form, degree, steps and solutions, the last two once solved.
+pub fn polynomial_section(equation: &str, polynomial: &Polynomial) -> String {
+    let mut lines: Vec<String> = vec![
+        "<section>".to_string(),
+        format!("<h2>{}</h2>", escape(equation)),
+        "<p>Reduced form:</p>".to_string(),
+        math_block(&format!(
+            "{}<mo>=</mo><mn>0</mn>",
+            reduced_form_to_mathml(polynomial)
+        )),
+        format!("<p>Polynomial degree: {}</p>", polynomial.degree),
+    ];
+    if !polynomial.steps.is_empty() {
+        lines.push("<ol>".to_string());
+        lines.extend(polynomial.steps.iter().map(step_to_html));
+        lines.push("</ol>".to_string());
+    }
+    if let Some(solutions) = &polynomial.solutions {
+        lines.extend(solution_set_to_html(polynomial, solutions));
+    }
+    lines.push("</section>".to_string());
+    lines.join("\n")
+}
+
+pub fn derivative_section(equation: &str, polynomial: &Polynomial) -> String {
+    calculus_section(
+        equation,
+        polynomial,
+        "Derivative:",
+        &reduced_form_to_mathml(&polynomial.derivative()),
+    )
+}
+
+pub fn antiderivative_section(equation: &str, polynomial: &Polynomial) -> String {
+    calculus_section(
+        equation,
+        polynomial,
+        "Antiderivative:",
+        &format!(
+            "{}<mo>+</mo><mi>C</mi>",
+            reduced_form_to_mathml(&polynomial.antiderivative())
+        ),
+    )
+}
+
+/// Error message with the offending part of the equation highlighted, and the hint.
+pub fn error_section(equation: &str, error: &ComputorError) -> String {
+    let mut lines: Vec<String> = vec![
+        "<section>".to_string(),
+        format!("<h2>{}</h2>", escape(equation)),
+        format!(
+            "<p class=\"error\">Error: {}.</p>",
+            escape(&error.to_string())
+        ),
+    ];
+    if let Some(span) = error.span() {
+        let start: usize = span.start.min(equation.len());
+        let end: usize = span.end.clamp(start, equation.len());
+        lines.push(format!(
+            "<p><code>{}<mark>{}</mark>{}</code></p>",
+            escape(&equation[..start]),
+            escape(&equation[start..end]),
+            escape(&equation[end..])
+        ));
+    }
+    if let Some(hint) = error.hint() {
+        lines.push(format!("<p>Hint: {}</p>", escape(&hint)));
+    }
+    lines.push("</section>".to_string());
+    lines.join("\n")
+}
+
+/// Terms by decreasing power, as in `3x² − 2x + 1`.
+pub fn reduced_form_to_mathml(polynomial: &Polynomial) -> String {
+    let mut terms: Vec<String> = Vec::new();
+    for (power, coefficient) in polynomial.coefficients.iter().enumerate().rev() {
+        if coefficient.is_zero() {
+            continue;
+        }
+        let magnitude: Rational = coefficient.abs();
+        let variable: String = match power {
+            0 => String::new(),
+            1 => "<mi>x</mi>".to_string(),
+            _ => format!("<msup><mi>x</mi><mn>{}</mn></msup>", power),
+        };
+        let term: String = match (power, magnitude == Rational::one()) {
+            (0, _) => rational_to_mathml(&magnitude),
+            (_, true) => variable,
+            (_, false) => format!(
+                "{}<mo>&#x2062;</mo>{}",
+                rational_to_mathml(&magnitude),
+                variable
+            ),
+        };
+        let sign: &str = match (terms.is_empty(), coefficient.is_negative()) {
+            (true, true) => "<mo>−</mo>",
+            (true, false) => "",
+            (false, true) => "<mo>−</mo>",
+            (false, false) => "<mo>+</mo>",
+        };
+        terms.push(format!("{}{}", sign, term));
+    }
+    if terms.is_empty() {
+        terms.push("<mn>0</mn>".to_string());
+    }
+    terms.concat()
+}
+
+/// Exact roots with fractions and square roots, complex ones as `a + bi`.
+pub fn root_to_mathml(root: &Root) -> String {
+    match root {
+        Root::Exact(value) => rational_to_mathml(value),
+        Root::Surd(value) => formula_elements(&Formula::from(value)),
+        Root::Approximate(value) => number_to_mathml(*value),
+        Root::Complex(value) => {
+            let modulus: f64 = value.imaginary.abs();
+            let imaginary: String = match modulus {
+                1.0 => "<mi>i</mi>".to_string(),
+                _ => format!("<mn>{}</mn><mo>&#x2062;</mo><mi>i</mi>", modulus),
+            };
+            complex_to_mathml(
+                value.real != 0.0,
+                &number_to_mathml(value.real),
+                &imaginary,
+                value.imaginary < 0.0,
+            )
+        }
+    }
+}
+
+/// Presentation MathML of a step formula, fractions and roots grouping their contents
+/// where the plain text needs parentheses.
+pub fn formula_to_mathml(formula: &Formula) -> String {
+    format!("<mrow>{}</mrow>", formula_elements(formula))
+}
+
+// Private part
+fn math_block(content: &str) -> String {
+    format!("<math display=\"block\"><mrow>{}</mrow></math>", content)
+}
+
+fn calculus_section(equation: &str, polynomial: &Polynomial, label: &str, result: &str) -> String {
+    [
+        "<section>".to_string(),
+        format!("<h2>{}</h2>", escape(equation)),
+        "<p>Reduced form:</p>".to_string(),
+        math_block(&reduced_form_to_mathml(polynomial)),
+        format!("<p>{}</p>", label),
+        math_block(result),
+        "</section>".to_string(),
+    ]
+    .join("\n")
+}
+
+/// `condition ⇒ description: formula = substitution = result (remark)`, the formulas as
+/// inline math.
+fn step_to_html(step: &Step) -> String {
+    let mut line: String = String::new();
+    if let Some(condition) = &step.condition {
+        line.push_str(&format!("<math>{}</math> ⇒ ", formula_to_mathml(condition)));
+    }
+    let parts: Vec<String> = step.parts().into_iter().map(formula_to_mathml).collect();
+    let math: String = format!("<math>{}</math>", parts.join("<mo>=</mo>"));
+    match (step.description.is_empty(), parts.is_empty()) {
+        (_, true) => line.push_str(&escape(&step.description)),
+        (true, false) => line.push_str(&math),
+        (false, false) => line.push_str(&format!("{}: {}", escape(&step.description), math)),
+    }
+    if let Some(remark) = &step.remark {
+        line.push_str(&format!(" ({})", escape(remark)));
+    }
+    format!("<li>{}</li>", line)
+}
+
+fn formula_elements(formula: &Formula) -> String {
+    match formula {
+        Formula::Symbol(name) => format!("<mi>{}</mi>", escape(name)),
+        Formula::Indexed(name, index) => {
+            format!("<msub><mi>{}</mi><mn>{}</mn></msub>", escape(name), index)
+        }
+        Formula::Exact(value) => rational_to_mathml(value),
+        Formula::Approximate(value) => number_to_mathml(*value),
+        Formula::Sum(terms) => {
+            let mut elements: String = String::new();
+            for (index, (sign, term)) in terms.iter().enumerate() {
+                let operator: &str = match (index, sign) {
+                    (0, Sign::Plus) => "",
+                    (_, Sign::Plus) => "<mo>+</mo>",
+                    (_, Sign::Minus) => "<mo>−</mo>",
+                    (_, Sign::PlusMinus) => "<mo>±</mo>",
+                };
+                elements.push_str(operator);
+                elements.push_str(&grouped_elements(term, Position::Term(*sign, index == 0)));
+            }
+            elements
+        }
+        Formula::Product(factors) => {
+            let factors: Vec<String> = factors
+                .iter()
+                .map(|factor| grouped_elements(factor, Position::Factor))
+                .collect();
+            factors.join("<mo>&#x2062;</mo>")
+        }
+        Formula::Times(factors) => {
+            let factors: Vec<String> = factors
+                .iter()
+                .map(|factor| grouped_elements(factor, Position::ExplicitFactor))
+                .collect();
+            factors.join("<mo>·</mo>")
+        }
+        Formula::Quotient(numerator, denominator) => format!(
+            "<mfrac>{}{}</mfrac>",
+            formula_to_mathml(numerator),
+            formula_to_mathml(denominator)
+        ),
+        Formula::Power(base, exponent) => format!(
+            "<msup><mrow>{}</mrow><mn>{}</mn></msup>",
+            grouped_elements(base, Position::Base),
+            exponent
+        ),
+        Formula::SquareRoot(radicand) => format!("<msqrt>{}</msqrt>", formula_elements(radicand)),
+        Formula::CubeRoot(radicand) => {
+            format!("<mroot>{}<mn>3</mn></mroot>", formula_to_mathml(radicand))
+        }
+        Formula::Function(name, argument) => format!(
+            "<mi>{}</mi><mo>&#x2061;</mo>{}",
+            escape(name),
+            parenthesized(&formula_elements(argument))
+        ),
+        Formula::Relation(left, relation, right) => {
+            let operator: &str = match relation {
+                Relation::Equal => "=",
+                Relation::NotEqual => "≠",
+                Relation::Less => "&lt;",
+                Relation::Greater => "&gt;",
+                Relation::GreaterOrEqual => "≥",
+            };
+            format!(
+                "{}<mo>{}</mo>{}",
+                formula_elements(left),
+                operator,
+                formula_elements(right)
+            )
+        }
+        Formula::And(conditions) => {
+            let conditions: Vec<String> = conditions.iter().map(formula_elements).collect();
+            conditions.join("<mtext>&#xa0;and&#xa0;</mtext>")
+        }
+        Formula::List(values) => {
+            let values: Vec<String> = values.iter().map(formula_elements).collect();
+            values.join("<mo>,</mo>")
+        }
+    }
+}
+
+/// Elements of `formula` in parentheses when its position requires them, fractions and
+/// roots already grouping their contents.
+fn grouped_elements(formula: &Formula, position: Position) -> String {
+    let elements: String = formula_elements(formula);
+    match formula.needs_parentheses(position) {
+        true => parenthesized(&elements),
+        false => elements,
+    }
+}
+
+fn parenthesized(elements: &str) -> String {
+    format!("<mrow><mo>(</mo>{}<mo>)</mo></mrow>", elements)
+}
+
+fn solution_set_to_html(polynomial: &Polynomial, solutions: &SolutionSet) -> Vec<String> {
+    match solutions {
+        SolutionSet::Contradiction => vec!["<p>No solution.</p>".to_string()],
+        SolutionSet::Identity => vec!["<p>Every real number is a solution.</p>".to_string()],
+        SolutionSet::Unsupported { reason } => {
+            vec![format!("<p>No solution found: {}.</p>", escape(reason))]
+        }
+        SolutionSet::Finite(solutions) => {
+            let mut lines: Vec<String> = vec!["<p>Solutions:</p>".to_string()];
+            for (index, solution) in solutions.iter().enumerate() {
+                let name: String = match solutions.len() {
+                    1 => "<mi>x</mi>".to_string(),
+                    _ => format!("<msub><mi>x</mi><mn>{}</mn></msub>", index + 1),
+                };
+                lines.push(math_block(&solution_to_mathml(polynomial, &name, solution)));
+            }
+            lines
+        }
+    }
+}
+
+fn solution_to_mathml(polynomial: &Polynomial, name: &str, solution: &Solution) -> String {
+    let root: String = match Formula::exact_root(polynomial, &solution.root) {
+        Some(exact) => formula_elements(&exact),
+        None => root_to_mathml(&solution.root),
+    };
+    let value: String = format!("{}<mo>=</mo>{}", name, root);
+    match solution.multiplicity {
+        1 => value,
+        multiplicity => format!(
+            "{}<mtext>&#xa0;(multiplicity {})</mtext>",
+            value, multiplicity
+        ),
+    }
+}
+
+fn complex_to_mathml(has_real: bool, real: &str, imaginary: &str, negative: bool) -> String {
+    match (has_real, negative) {
+        (false, false) => imaginary.to_string(),
+        (false, true) => format!("<mo>−</mo>{}", imaginary),
+        (true, false) => format!("{}<mo>+</mo>{}", real, imaginary),
+        (true, true) => format!("{}<mo>−</mo>{}", real, imaginary),
+    }
+}
+
+/// Decimal for terminating values, as in the text output, a fraction otherwise.
+fn rational_to_mathml(value: &Rational) -> String {
+    let sign: &str = if value.is_negative() {
+        "<mo>−</mo>"
+    } else {
+        ""
+    };
+    match value.abs().to_decimal() {
+        Some(decimal) => format!("{}<mn>{}</mn>", sign, decimal),
+        None => format!(
+            "{}<mfrac><mn>{}</mn><mn>{}</mn></mfrac>",
+            sign,
+            value.numerator().abs(),
+            value.denominator()
+        ),
+    }
+}
+
+fn number_to_mathml(value: f64) -> String {
+    if value < 0.0 {
+        format!("<mo>−</mo><mn>{}</mn>", -value)
+    } else {
+        format!("<mn>{}</mn>", value)
+    }
+}
+
+/// Characters with a special meaning in HTML.
+fn escape(text: &str) -> String {
+    let mut escaped: String = String::new();
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,203 @@
+use crate::errors::ComputorError;
+use crate::math_tools::polynomial::Polynomial;
+use crate::math_tools::solution::{Solution, SolutionSet};
+use crate::solvers::formula::Formula;
+use crate::solvers::quadratic::discriminant;
+use std::fmt;
+
+/// JSON value, printed compactly on a single line.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Same object with `key` added in front, other values being left unchanged.
+    pub fn with_field(self, key: &str, value: Json) -> Self {
+        match self {
+            Json::Object(mut fields) => {
+                fields.insert(0, (key.to_string(), value));
+                Json::Object(fields)
+            }
+            other => other,
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl From<usize> for Json {
+    fn from(value: usize) -> Self {
+        Json::Number(value as f64)
+    }
+}
+
+impl From<f64> for Json {
+    fn from(value: f64) -> Self {
+        Json::Number(value)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(value) => write!(f, "{}", value),
+            // JSON has no representation for NaN or the infinities.
+            Json::Number(value) if !value.is_finite() => write!(f, "null"),
+            Json::Number(value) => write!(f, "{}", value),
+            Json::String(value) => write!(f, "{}", escape(value)),
+            Json::Array(values) => {
+                let values: Vec<String> = values.iter().map(Json::to_string).collect();
+                write!(f, "[{}]", values.join(","))
+            }
+            Json::Object(fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|(key, value)| format!("{}:{}", escape(key), value))
+                    .collect();
+                write!(f, "{{{}}}", fields.join(","))
+            }
+        }
+    }
+}
+
+/// Document for a parsed equation: its reduced form, degree, discriminant for a
+/// quadratic, derivation steps and solution set, `null` until it is solved.
+pub fn polynomial_to_json(equation: &str, polynomial: &Polynomial) -> Json {
+    let coefficients: Vec<Json> = polynomial
+        .coefficients
+        .iter()
+        .map(|coefficient| Json::from(coefficient.to_fraction()))
+        .collect();
+    let discriminant: Json = match (polynomial.degree, &polynomial.solutions) {
+        (2, Some(_)) => Json::from(discriminant(&polynomial.coefficients).to_fraction()),
+        _ => Json::Null,
+    };
+    let steps: Vec<Json> = polynomial
+        .steps
+        .iter()
+        .map(|step| Json::from(step.to_string()))
+        .collect();
+    let solutions: Json = match &polynomial.solutions {
+        Some(solutions) => solution_set_to_json(polynomial, solutions),
+        None => Json::Null,
+    };
+
+    Json::Object(vec![
+        ("input".to_string(), Json::from(equation)),
+        ("coefficients".to_string(), Json::Array(coefficients)),
+        ("degree".to_string(), Json::from(polynomial.degree)),
+        ("discriminant".to_string(), discriminant),
+        ("steps".to_string(), Json::Array(steps)),
+        ("solutions".to_string(), solutions),
+        ("error".to_string(), Json::Null),
+    ])
+}
+
+/// Document for an equation that could not be parsed, with the same fields as a solved
+/// one.
+pub fn error_to_json(equation: &str, error: &ComputorError) -> Json {
+    let span: (Json, Json) = match error.span() {
+        Some(span) => (Json::from(span.start), Json::from(span.end)),
+        None => (Json::Null, Json::Null),
+    };
+    let error: Json = Json::Object(vec![
+        ("message".to_string(), Json::from(error.to_string())),
+        ("start".to_string(), span.0),
+        ("end".to_string(), span.1),
+        ("hint".to_string(), Json::from(error.hint())),
+    ]);
+
+    Json::Object(vec![
+        ("input".to_string(), Json::from(equation)),
+        ("coefficients".to_string(), Json::Null),
+        ("degree".to_string(), Json::Null),
+        ("discriminant".to_string(), Json::Null),
+        ("steps".to_string(), Json::Null),
+        ("solutions".to_string(), Json::Null),
+        ("error".to_string(), error),
+    ])
+}
+
+/// Private part
+fn solution_set_to_json(polynomial: &Polynomial, solutions: &SolutionSet) -> Json {
+    let (kind, roots, reason): (&str, Json, Json) = match solutions {
+        SolutionSet::Contradiction => ("contradiction", Json::Array(Vec::new()), Json::Null),
+        SolutionSet::Identity => ("identity", Json::Null, Json::Null),
+        SolutionSet::Finite(solutions) => (
+            "finite",
+            Json::Array(
+                solutions
+                    .iter()
+                    .map(|solution| solution_to_json(polynomial, solution))
+                    .collect(),
+            ),
+            Json::Null,
+        ),
+        SolutionSet::Unsupported { reason } => {
+            ("unsupported", Json::Null, Json::from(reason.as_str()))
+        }
+    };
+    Json::Object(vec![
+        ("type".to_string(), Json::from(kind)),
+        ("roots".to_string(), roots),
+        ("reason".to_string(), reason),
+    ])
+}
+
+fn solution_to_json(polynomial: &Polynomial, solution: &Solution) -> Json {
+    let exact: Option<String> =
+        Formula::exact_root(polynomial, &solution.root).map(|formula| formula.to_string());
+    let value = solution.root.to_complex();
+
+    Json::Object(vec![
+        ("real".to_string(), Json::from(value.real)),
+        ("imaginary".to_string(), Json::from(value.imaginary)),
+        ("is_real".to_string(), Json::Bool(solution.root.is_real())),
+        (
+            "multiplicity".to_string(),
+            Json::from(solution.multiplicity),
+        ),
+        ("exact".to_string(), Json::from(exact)),
+    ])
+}
+
+/// Quoted string with the characters JSON requires to be escaped.
+fn escape(value: &str) -> String {
+    let mut escaped: String = String::from("\"");
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
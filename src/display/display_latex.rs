@@ -0,0 +1,314 @@
+use crate::errors::ComputorError;
+use crate::math_tools::polynomial::Polynomial;
+use crate::math_tools::rational::Rational;
+use crate::math_tools::root::Root;
+use crate::math_tools::solution::{Solution, SolutionSet};
+use crate::solvers::derivation::Step;
+use crate::solvers::formula::{Formula, Position, Relation, Sign};
+
+/// Display math lines for a handout: the equation, then the derivation steps and the
+/// solutions once solved.
+pub fn polynomial_to_latex(polynomial: &Polynomial) -> String {
+    let mut lines: Vec<String> = vec![format!("\\[ {} = 0 \\]", reduced_form_to_latex(polynomial))];
+    lines.extend(polynomial.steps.iter().map(step_to_latex));
+    if let Some(solutions) = &polynomial.solutions {
+        lines.push(format!(
+            "\\[ {} \\]",
+            solution_set_to_latex(polynomial, solutions)
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Terms by decreasing power, as in `3x^{2} - 2x + 1`.
+pub fn reduced_form_to_latex(polynomial: &Polynomial) -> String {
+    let mut terms: Vec<String> = Vec::new();
+    for (power, coefficient) in polynomial.coefficients.iter().enumerate().rev() {
+        if coefficient.is_zero() {
+            continue;
+        }
+        let magnitude: String = rational_to_latex(&coefficient.abs());
+        let term: String = match (power, magnitude.as_str()) {
+            (0, _) => magnitude,
+            (1, "1") => "x".to_string(),
+            (1, _) => format!("{}x", magnitude),
+            (_, "1") => format!("x^{{{}}}", power),
+            (_, _) => format!("{}x^{{{}}}", magnitude, power),
+        };
+        let sign: &str = match (terms.is_empty(), coefficient.is_negative()) {
+            (true, true) => "-",
+            (true, false) => "",
+            (false, true) => " - ",
+            (false, false) => " + ",
+        };
+        terms.push(format!("{}{}", sign, term));
+    }
+    if terms.is_empty() {
+        terms.push("0".to_string());
+    }
+    terms.concat()
+}
+
+pub fn derivative_to_latex(polynomial: &Polynomial) -> String {
+    format!(
+        "\\[ \\frac{{d}}{{dx}}\\left({}\\right) = {} \\]",
+        reduced_form_to_latex(polynomial),
+        reduced_form_to_latex(&polynomial.derivative())
+    )
+}
+
+pub fn antiderivative_to_latex(polynomial: &Polynomial) -> String {
+    format!(
+        "\\[ \\int \\left({}\\right) dx = {} + C \\]",
+        reduced_form_to_latex(polynomial),
+        reduced_form_to_latex(&polynomial.antiderivative())
+    )
+}
+
+/// Exact roots with `\frac` and `\sqrt`, complex ones as `a + bi`.
+pub fn root_to_latex(root: &Root) -> String {
+    match root {
+        Root::Exact(value) => rational_to_latex(value),
+        Root::Surd(value) => formula_to_latex(&Formula::from(value)),
+        Root::Approximate(value) => value.to_string(),
+        Root::Complex(value) => {
+            let imaginary: String = match value.imaginary.abs() {
+                1.0 => "i".to_string(),
+                modulus => format!("{}i", modulus),
+            };
+            match (value.real == 0.0, value.imaginary < 0.0) {
+                (true, false) => imaginary,
+                (true, true) => format!("-{}", imaginary),
+                (false, false) => format!("{} + {}", value.real, imaginary),
+                (false, true) => format!("{} - {}", value.real, imaginary),
+            }
+        }
+    }
+}
+
+/// Error message with the offending part of the equation underlined, and the hint, as
+/// text lines so that a handout shows them in place of the equation.
+pub fn error_to_latex(equation: &str, error: &ComputorError) -> String {
+    let mut lines: Vec<String> = vec![format!(
+        "\\[ \\text{{Error: {}.}} \\]",
+        escape_text(&error.to_string())
+    )];
+    if let Some(span) = error.span() {
+        let start: usize = span.start.min(equation.len());
+        let end: usize = span.end.clamp(start, equation.len());
+        lines.push(format!(
+            "\\[ {}\\underline{{\\texttt{{{}}}}}{} \\]",
+            typewriter(&equation[..start]),
+            escape_text(&equation[start..end]),
+            typewriter(&equation[end..])
+        ));
+    }
+    if let Some(hint) = error.hint() {
+        lines.push(format!("\\[ \\text{{Hint: {}}} \\]", escape_text(&hint)));
+    }
+    lines.join("\n")
+}
+
+/// Step formula with `\frac` and `\sqrt`, Greek letters as commands.
+pub fn formula_to_latex(formula: &Formula) -> String {
+    match formula {
+        Formula::Symbol(name) => symbol_to_latex(name).to_string(),
+        Formula::Indexed(name, index) => format!("{}_{{{}}}", symbol_to_latex(name), index),
+        Formula::Exact(value) => rational_to_latex(value),
+        Formula::Approximate(value) => value.to_string(),
+        Formula::Sum(terms) => {
+            let mut latex: String = String::new();
+            for (index, (sign, term)) in terms.iter().enumerate() {
+                let operator: &str = match (index, sign) {
+                    (0, Sign::Plus) => "",
+                    (0, Sign::Minus) => "-",
+                    (0, Sign::PlusMinus) => "\\pm ",
+                    (_, Sign::Plus) => " + ",
+                    (_, Sign::Minus) => " - ",
+                    (_, Sign::PlusMinus) => " \\pm ",
+                };
+                latex.push_str(operator);
+                latex.push_str(&grouped_latex(term, Position::Term(*sign, index == 0)));
+            }
+            latex
+        }
+        Formula::Product(factors) => {
+            let mut latex: String = String::new();
+            for (index, factor) in factors.iter().enumerate() {
+                // `\Delta a` rather than the undefined `\Deltaa`.
+                if index > 0
+                    && matches!(&factors[index - 1], Formula::Symbol(name) if symbol_to_latex(name) != name)
+                {
+                    latex.push(' ');
+                }
+                latex.push_str(&grouped_latex(factor, Position::Factor));
+            }
+            latex
+        }
+        Formula::Times(factors) => {
+            let factors: Vec<String> = factors
+                .iter()
+                .map(|factor| grouped_latex(factor, Position::ExplicitFactor))
+                .collect();
+            factors.join(" \\cdot ")
+        }
+        Formula::Quotient(numerator, denominator) => format!(
+            "\\frac{{{}}}{{{}}}",
+            formula_to_latex(numerator),
+            formula_to_latex(denominator)
+        ),
+        Formula::Power(base, exponent) => {
+            format!("{}^{{{}}}", grouped_latex(base, Position::Base), exponent)
+        }
+        Formula::SquareRoot(radicand) => format!("\\sqrt{{{}}}", formula_to_latex(radicand)),
+        Formula::CubeRoot(radicand) => format!("\\sqrt[3]{{{}}}", formula_to_latex(radicand)),
+        Formula::Function(name, argument) => format!(
+            "\\operatorname{{{}}}\\left({}\\right)",
+            name,
+            formula_to_latex(argument)
+        ),
+        Formula::Relation(left, relation, right) => {
+            let operator: &str = match relation {
+                Relation::Equal => "=",
+                Relation::NotEqual => "\\neq",
+                Relation::Less => "<",
+                Relation::Greater => ">",
+                Relation::GreaterOrEqual => "\\geq",
+            };
+            format!(
+                "{} {} {}",
+                formula_to_latex(left),
+                operator,
+                formula_to_latex(right)
+            )
+        }
+        Formula::And(conditions) => {
+            let conditions: Vec<String> = conditions.iter().map(formula_to_latex).collect();
+            conditions.join(" \\text{ and } ")
+        }
+        Formula::List(values) => {
+            let values: Vec<String> = values.iter().map(formula_to_latex).collect();
+            values.join(", ")
+        }
+    }
+}
+
+// Private part
+/// `condition \Rightarrow \text{description: } formula = substitution = result`, the
+/// remark after it as text.
+fn step_to_latex(step: &Step) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    if let Some(condition) = &step.condition {
+        parts.push(format!("{} \\Rightarrow", formula_to_latex(condition)));
+    }
+    let formulas: Vec<String> = step.parts().into_iter().map(formula_to_latex).collect();
+    match (step.description.is_empty(), formulas.is_empty()) {
+        (true, _) => {}
+        (false, true) => parts.push(format!("\\text{{{}}}", escape_text(&step.description))),
+        (false, false) => parts.push(format!("\\text{{{}: }}", escape_text(&step.description))),
+    }
+    if !formulas.is_empty() {
+        parts.push(formulas.join(" = "));
+    }
+    if let Some(remark) = &step.remark {
+        parts.push(format!("\\text{{ ({})}}", escape_text(remark)));
+    }
+    format!("\\[ {} \\]", parts.join(" "))
+}
+
+fn symbol_to_latex(name: &str) -> &str {
+    match name {
+        "∆" => "\\Delta",
+        "θ" => "\\theta",
+        "π" => "\\pi",
+        letter => letter,
+    }
+}
+
+/// LaTeX of `formula` in parentheses when its position requires them, fractions and
+/// roots already grouping their contents.
+fn grouped_latex(formula: &Formula, position: Position) -> String {
+    let latex: String = formula_to_latex(formula);
+    match formula.needs_parentheses(position) {
+        true => format!("({})", latex),
+        false => latex,
+    }
+}
+
+fn solution_set_to_latex(polynomial: &Polynomial, solutions: &SolutionSet) -> String {
+    match solutions {
+        SolutionSet::Contradiction => "\\text{No solution.}".to_string(),
+        SolutionSet::Identity => "\\text{Every real number is a solution.}".to_string(),
+        SolutionSet::Unsupported { reason } => {
+            format!("\\text{{No solution found: {}.}}", escape_text(reason))
+        }
+        SolutionSet::Finite(solutions) => {
+            let lines: Vec<String> = solutions
+                .iter()
+                .enumerate()
+                .map(|(index, solution)| {
+                    let name: String = match solutions.len() {
+                        1 => "x".to_string(),
+                        _ => format!("x_{{{}}}", index + 1),
+                    };
+                    solution_to_latex(polynomial, &name, solution)
+                })
+                .collect();
+            lines.join(", \\quad ")
+        }
+    }
+}
+
+fn solution_to_latex(polynomial: &Polynomial, name: &str, solution: &Solution) -> String {
+    let root: String = match Formula::exact_root(polynomial, &solution.root) {
+        Some(exact) => formula_to_latex(&exact),
+        None => root_to_latex(&solution.root),
+    };
+    let value: String = format!("{} = {}", name, root);
+    match solution.multiplicity {
+        1 => value,
+        multiplicity => format!("{} \\text{{ (multiplicity {})}}", value, multiplicity),
+    }
+}
+
+/// Decimal for terminating values, as in the reduced form, `\frac` otherwise.
+fn rational_to_latex(value: &Rational) -> String {
+    match value.to_decimal() {
+        Some(decimal) => decimal,
+        None => {
+            let sign: &str = if value.is_negative() { "-" } else { "" };
+            format!(
+                "{}\\frac{{{}}}{{{}}}",
+                sign,
+                value.numerator().abs(),
+                value.denominator()
+            )
+        }
+    }
+}
+
+/// Equation text in a typewriter font, nothing for an empty one.
+fn typewriter(text: &str) -> String {
+    match text.is_empty() {
+        true => String::new(),
+        false => format!("\\texttt{{{}}}", escape_text(text)),
+    }
+}
+
+/// Characters with a special meaning in LaTeX text mode.
+fn escape_text(text: &str) -> String {
+    let mut escaped: String = String::new();
+    for character in text.chars() {
+        match character {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '{' | '}' | '%' | '&' | '_' | '#' | '$' => {
+                escaped.push('\\');
+                escaped.push(character);
+            }
+            '^' => escaped.push_str("\\^{}"),
+            '~' => escaped.push_str("\\~{}"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -1,20 +1,29 @@
-use crate::math_tools::basic;
+use crate::math_tools::factorization::factorize;
 use crate::math_tools::polynomial::Polynomial;
+use crate::math_tools::rational::Rational;
+use crate::math_tools::root::Root;
+use crate::math_tools::solution::{Solution, SolutionSet};
+use crate::math_tools::verification::{verify_solutions, Verification};
 
 pub fn display_solved_polynomial(polynomial: &Polynomial) {
     display_reduced_form(polynomial);
+    display_factored_form(polynomial);
     display_degree(polynomial);
+    display_steps(polynomial);
     display_solutions(polynomial);
 }
 
-/// Private part
-fn display_reduced_form(polynomial: &Polynomial) {
+pub fn display_reduced_form(polynomial: &Polynomial) {
+    println!("Reduced form: {}", format_reduced_form(polynomial));
+}
+
+/// Terms by increasing power, as in `4 + 4*X - 9.3*X^2`.
+pub fn format_reduced_form(polynomial: &Polynomial) -> String {
     let mut terms: Vec<String> = Vec::new();
 
     for (index, coefficient) in polynomial.coefficients.iter().enumerate() {
-        let value: f64 = *coefficient;
-        if value != 0.0 {
-            let mut term: String = format!("{}", value);
+        if !coefficient.is_zero() {
+            let mut term: String = format!("{}", coefficient);
             if index > 0 {
                 term.push_str("*X");
                 if index > 1 {
@@ -25,53 +34,124 @@ fn display_reduced_form(polynomial: &Polynomial) {
             terms.push(term);
         }
     }
-    let reduced_form = terms.join(" + ").replace("+ -", "- ");
-    println!("Reduced form: {}", reduced_form);
+    if terms.is_empty() {
+        terms.push("0".to_string());
+    }
+    terms.join(" + ").replace("+ -", "- ")
+}
+
+/// Only for polynomials with a root, a constant having nothing to factor.
+pub fn display_factored_form(polynomial: &Polynomial) {
+    if polynomial.degree > 0 {
+        println!("Factored form: {}", factorize(polynomial));
+    }
+}
+
+pub fn display_derivative(polynomial: &Polynomial) {
+    display_reduced_form(polynomial);
+    println!(
+        "Derivative: {}",
+        format_reduced_form(&polynomial.derivative())
+    );
 }
 
-fn display_degree(polynomial: &Polynomial) {
+pub fn display_antiderivative(polynomial: &Polynomial) {
+    display_reduced_form(polynomial);
+    println!(
+        "Antiderivative: {} + C",
+        format_reduced_form(&polynomial.antiderivative())
+    );
+}
+
+pub fn display_degree(polynomial: &Polynomial) {
     println!("Polynomial degree: {}", polynomial.degree);
 }
 
-fn display_solutions(polynomial: &Polynomial) {
-    print!("Solutions:");
-    match &polynomial.solutions {
-        Some(solutions) if solutions.is_empty() => println!("no solutions."),
-        Some(solutions) => {
-            if polynomial.degree == 2 && polynomial.discriminant < 0.0 {
-                display_complex_solutions(solutions);
-            } else {
-                display_real_solutions(solutions);
-            }
-        }
-        None => {
-            if polynomial.degree == 1 {
-                println!(" all real numbers are solutions.")
-            } else {
-                println!(" None")
-            }
+pub fn display_steps(polynomial: &Polynomial) {
+    for step in &polynomial.steps {
+        println!("{}", step);
+    }
+}
+
+/// Residual of each solution substituted into the reduced form, flagging the ones above
+/// the tolerance.
+pub fn display_verification(polynomial: &Polynomial) {
+    let verifications: Vec<Verification> = verify_solutions(polynomial);
+    if verifications.is_empty() {
+        println!("Verification: no solution to substitute.");
+        return;
+    }
+    println!("Verification:");
+    for verification in verifications {
+        let residual: String = if verification.residual == 0.0 {
+            "0".to_string()
+        } else {
+            format!("{:.1e}", verification.residual)
+        };
+        if verification.accepted {
+            println!("P({}) = {}", verification.root, residual);
+        } else {
+            println!(
+                "P({}) = {}, above the tolerance, not a solution",
+                verification.root, residual
+            );
         }
     }
 }
 
-fn display_complex_solutions(solutions: &[f64]) {
-    let real_part: f64 = solutions[0];
-    let imaginary_part: f64 = solutions[1];
+/// Distinct real roots counted with the Sturm sequence, each in its isolating interval.
+pub fn display_real_root_intervals(polynomial: &Polynomial) {
+    if polynomial.is_zero() {
+        println!("Distinct real roots (Sturm sequence): every real number.");
+        return;
+    }
+    let intervals: Vec<(Rational, Rational)> = polynomial.isolate_real_roots();
+    println!("Distinct real roots (Sturm sequence): {}", intervals.len());
+    for (low, high) in intervals {
+        println!("One root in ({}, {}]", low, high);
+    }
+}
 
-    println!(
-        "{} + i * {} and {} - i * {}",
-        real_part, imaginary_part, real_part, imaginary_part
-    );
+/// Private part
+fn display_solutions(polynomial: &Polynomial) {
+    print!("Solutions:");
+    match &polynomial.solutions {
+        Some(SolutionSet::Contradiction) => println!(" none, the equation is never true."),
+        Some(SolutionSet::Identity) => println!(" all real numbers are solutions."),
+        Some(SolutionSet::Finite(solutions)) => display_each_solution(solutions),
+        Some(SolutionSet::Unsupported { reason }) => println!(" unknown, {}.", reason),
+        None => println!(" not computed."),
+    }
 }
 
-fn display_real_solutions(solutions: &[f64]) {
+fn display_each_solution(solutions: &[Solution]) {
     for solution in solutions {
-        print!(" {} ", solution);
-        if let Some((numerator, denominator)) = basic::convert_to_irreducible(*solution) {
-            if denominator != 1 {
-                print!("({}/{})", numerator, denominator);
+        print!(" {} ", solution.root);
+        if let Root::Exact(value) = &solution.root {
+            if !value.is_integer() {
+                print!("({}) ", value.to_fraction());
             }
         }
+        if solution.multiplicity > 1 {
+            print!("(multiplicity {}) ", solution.multiplicity);
+        }
     }
     println!();
+    display_exact_form(solutions);
+}
+
+fn display_exact_form(solutions: &[Solution]) {
+    let roots: Vec<&Root> = solutions.iter().map(|solution| &solution.root).collect();
+    match roots.as_slice() {
+        [Root::Surd(x1), Root::Surd(x2)] if x1.conjugate() == *x2 => {
+            println!("Exact form: x = {}", x2.format_conjugates())
+        }
+        _ => {
+            for root in roots {
+                if let Root::Surd(value) = root {
+                    println!("Exact form: x = {}", value);
+                }
+            }
+        }
+    }
 }
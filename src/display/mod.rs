@@ -1 +1,5 @@
+pub mod display_error;
+pub mod display_html;
+pub mod display_json;
+pub mod display_latex;
 pub mod display_polynomial;
@@ -0,0 +1,166 @@
+use crate::constants::parsing_constants::{
+    DEGREE_TOO_HIGH, DIVISION_BY_ZERO, EMPTY_INPUT, EXAMPLE, INVALID_COEFFICIENT, INVALID_POWER,
+    MAX_DEGREE, NON_CONSTANT_DIVISOR, UNCLOSED_PARENTHESIS, UNEXPECTED_CHARACTER, UNEXPECTED_END,
+    UNEXPECTED_TOKEN,
+};
+use std::error::Error;
+use std::fmt;
+
+/// Byte range `start..end` of a token in the parsed equation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Span { start, end }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComputorError {
+    EmptyInput,
+    InvalidCoefficient { text: String, span: Span },
+    InvalidPower { text: String, span: Span },
+    UnexpectedCharacter { character: char, span: Span },
+    UnexpectedToken { text: String, span: Span },
+    UnexpectedEnd { span: Span },
+    UnclosedParenthesis { span: Span },
+    DivisionByZero { text: String, span: Span },
+    NonConstantDivisor { text: String, span: Span },
+    DegreeTooHigh { text: String, span: Span },
+}
+
+impl ComputorError {
+    /// Location of the offending token, when the error comes from the parser.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            ComputorError::InvalidCoefficient { span, .. }
+            | ComputorError::InvalidPower { span, .. }
+            | ComputorError::UnexpectedCharacter { span, .. }
+            | ComputorError::UnexpectedToken { span, .. }
+            | ComputorError::UnexpectedEnd { span }
+            | ComputorError::UnclosedParenthesis { span }
+            | ComputorError::DivisionByZero { span, .. }
+            | ComputorError::NonConstantDivisor { span, .. }
+            | ComputorError::DegreeTooHigh { span, .. } => Some(*span),
+            ComputorError::EmptyInput => None,
+        }
+    }
+
+    /// Suggestion shown under the diagnostic, when the mistake looks like a common one.
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            ComputorError::EmptyInput => Some(format!("try something like \"{}\".", EXAMPLE)),
+            ComputorError::InvalidCoefficient { text, .. } => coefficient_hint(text),
+            ComputorError::InvalidPower { text, .. } => power_hint(text),
+            ComputorError::UnexpectedCharacter { character, .. } => character_hint(*character),
+            ComputorError::UnexpectedToken { text, .. } => token_hint(text),
+            ComputorError::UnexpectedEnd { .. } => {
+                Some("the equation ends with an operator, an operand is missing.".to_string())
+            }
+            ComputorError::UnclosedParenthesis { .. } => {
+                Some("this '(' is never closed, add the matching ')'.".to_string())
+            }
+            ComputorError::DivisionByZero { .. } => None,
+            ComputorError::NonConstantDivisor { .. } => Some(
+                "only divisions by a constant are supported, multiply both members instead."
+                    .to_string(),
+            ),
+            ComputorError::DegreeTooHigh { .. } => {
+                Some(format!("powers and degrees are limited to {}.", MAX_DEGREE))
+            }
+        }
+    }
+}
+
+/// Private part
+fn coefficient_hint(text: &str) -> Option<String> {
+    match text.find('X') {
+        Some(0) => Some(format!(
+            "the coefficient goes before the variable, e.g. 3 * {}.",
+            text
+        )),
+        Some(index) if text[..index].parse::<f64>().is_ok() => Some(format!(
+            "did you mean {} * {}?",
+            &text[..index],
+            &text[index..]
+        )),
+        _ => None,
+    }
+}
+
+fn power_hint(text: &str) -> Option<String> {
+    if let Some((base, exponent)) = text.rsplit_once('^') {
+        return match exponent.parse::<f64>() {
+            Ok(value) if value >= 0.0 && value.round() <= MAX_DEGREE as f64 => Some(format!(
+                "powers must be non-negative integers, did you mean {}^{}?",
+                base,
+                value.round()
+            )),
+            Ok(value) if value >= 0.0 => Some(degree_hint()),
+            _ if exponent.is_empty() => Some(format!("the power is missing, e.g. {}^2.", base)),
+            _ => Some("powers must be non-negative integers, e.g. X^2.".to_string()),
+        };
+    }
+    match text.strip_prefix('X').map(str::parse::<usize>) {
+        Some(Ok(exponent)) if exponent <= MAX_DEGREE => {
+            Some(format!("did you mean X^{}?", exponent))
+        }
+        _ => None,
+    }
+}
+
+fn degree_hint() -> String {
+    format!("powers and degrees are limited to {}.", MAX_DEGREE)
+}
+
+fn token_hint(text: &str) -> Option<String> {
+    match text {
+        ")" => Some("this ')' has no matching '('.".to_string()),
+        "=" => Some("an operand is missing before '='.".to_string()),
+        _ => None,
+    }
+}
+
+fn character_hint(character: char) -> Option<String> {
+    match character {
+        'x' => Some("the variable is written X, lowercase is accepted with --natural.".to_string()),
+        '²' | '³' => Some("superscript powers are accepted with --natural.".to_string()),
+        ',' => Some("use '.' as the decimal separator.".to_string()),
+        '=' => Some("an equation contains a single '='.".to_string()),
+        c if c.is_alphabetic() => Some("the only variable allowed is X.".to_string()),
+        _ => None,
+    }
+}
+
+impl fmt::Display for ComputorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComputorError::EmptyInput => write!(f, "{}", EMPTY_INPUT),
+            ComputorError::InvalidCoefficient { text, .. } => {
+                write!(f, "{}{}", INVALID_COEFFICIENT, text)
+            }
+            ComputorError::InvalidPower { text, .. } => write!(f, "{}{}", INVALID_POWER, text),
+            ComputorError::UnexpectedCharacter { character, .. } => {
+                write!(f, "{}'{}'", UNEXPECTED_CHARACTER, character)
+            }
+            ComputorError::UnexpectedToken { text, .. } => {
+                write!(f, "{}'{}'", UNEXPECTED_TOKEN, text)
+            }
+            ComputorError::UnexpectedEnd { .. } => write!(f, "{}", UNEXPECTED_END),
+            ComputorError::UnclosedParenthesis { .. } => write!(f, "{}", UNCLOSED_PARENTHESIS),
+            ComputorError::DivisionByZero { text, .. } => {
+                write!(f, "{}{}", DIVISION_BY_ZERO, text)
+            }
+            ComputorError::NonConstantDivisor { text, .. } => {
+                write!(f, "{}{}", NON_CONSTANT_DIVISOR, text)
+            }
+            ComputorError::DegreeTooHigh { text, .. } => write!(f, "{}{}", DEGREE_TOO_HIGH, text),
+        }
+    }
+}
+
+impl Error for ComputorError {}
@@ -0,0 +1,2 @@
+pub mod computor_error;
+pub use crate::errors::computor_error::{ComputorError, Span};
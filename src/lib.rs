@@ -1,7 +1,10 @@
 extern crate core;
 
+pub mod batch;
 pub mod constants;
 pub mod display;
+pub mod errors;
 pub mod math_tools;
 pub mod parser;
+pub mod repl;
 pub mod solvers;
@@ -1,25 +1,333 @@
-use computorv1::constants::parsing_constants::INVALID_ARG_NUMBER;
-use computorv1::display::display_polynomial;
+use computorv1::batch::batch_runner::{run_batch, BatchSummary};
+use computorv1::constants::display_constants::{HTML_HEAD, HTML_TAIL};
+use computorv1::constants::parsing_constants::{
+    DERIVE_COMMAND, FILE_FLAGS, FORMAT_FLAGS, HTML_FORMAT, INTEGRATE_COMMAND, INVALID_ARG_NUMBER,
+    INVALID_FORMAT, INVALID_OPTION, JSON_FORMAT, LATEX_FORMAT, MISSING_VALUE, NATURAL_FLAGS,
+    NUMERICAL_FLAGS, STDIN_PATH, STURM_FLAGS, TEXT_FORMAT, UNREADABLE_FILE, VERIFY_FLAGS,
+};
+use computorv1::display::display_html::{
+    antiderivative_section, derivative_section, error_section, polynomial_section,
+};
+use computorv1::display::display_json::{error_to_json, polynomial_to_json, Json};
+use computorv1::display::display_latex::{
+    antiderivative_to_latex, derivative_to_latex, error_to_latex, polynomial_to_latex,
+};
+use computorv1::display::{display_error, display_polynomial};
 use computorv1::math_tools::polynomial::Polynomial;
+use computorv1::math_tools::solution::SolutionSet;
+use computorv1::parser::ParseMode;
+use computorv1::repl::session;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
 use std::{env, process};
 
-fn handle_error(message: &str) -> ! {
+fn handle_error(message: &dyn Display) -> ! {
     println!("Error: {message}.");
     process::exit(1);
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+enum Command {
+    Solve,
+    Derive,
+    Integrate,
+}
+
+/// Result of one equation, anything but a solved equation failing both alone and in a batch.
+#[derive(PartialEq)]
+enum Outcome {
+    Solved,
+    Unsolved,
+    InvalidEquation,
+}
+
+impl Outcome {
+    fn is_success(&self) -> bool {
+        *self == Outcome::Solved
+    }
+}
+
+enum OutputFormat {
+    Text,
+    Json,
+    Latex,
+    Html,
+}
+
+struct Options<'a> {
+    command: Command,
+    format: OutputFormat,
+    mode: ParseMode,
+    numerical: bool,
+    verify: bool,
+    sturm: bool,
+    equation: Option<&'a String>,
+    file: Option<&'a String>,
+}
+
+/// An optional command comes first, then the options, and the equation last unless the
+/// equations are read from a file.
+fn parse_arguments(args: &[String]) -> Result<Options<'_>, String> {
+    let (command, arguments): (Command, &[String]) = match args.get(1).map(String::as_str) {
+        Some(DERIVE_COMMAND) => (Command::Derive, &args[2..]),
+        Some(INTEGRATE_COMMAND) => (Command::Integrate, &args[2..]),
+        _ => (Command::Solve, args.get(1..).unwrap_or_default()),
+    };
+    let mut options: Options = Options {
+        command,
+        format: OutputFormat::Text,
+        mode: ParseMode::Strict,
+        numerical: false,
+        verify: false,
+        sturm: false,
+        equation: None,
+        file: None,
+    };
+
+    let mut arguments = arguments.iter().peekable();
+    while let Some(flag) = arguments.next() {
+        if NATURAL_FLAGS.contains(&flag.as_str()) {
+            options.mode = ParseMode::Natural;
+        } else if NUMERICAL_FLAGS.contains(&flag.as_str()) {
+            options.numerical = true;
+        } else if VERIFY_FLAGS.contains(&flag.as_str()) {
+            options.verify = true;
+        } else if STURM_FLAGS.contains(&flag.as_str()) {
+            options.sturm = true;
+        } else if FILE_FLAGS.contains(&flag.as_str()) {
+            let file: &String = arguments
+                .next()
+                .ok_or_else(|| format!("{}{}", MISSING_VALUE, flag))?;
+            options.file = Some(file);
+        } else if FORMAT_FLAGS.contains(&flag.as_str()) {
+            options.format = match arguments.next().map(String::as_str) {
+                Some(TEXT_FORMAT) => OutputFormat::Text,
+                Some(JSON_FORMAT) => OutputFormat::Json,
+                Some(LATEX_FORMAT) => OutputFormat::Latex,
+                Some(HTML_FORMAT) => OutputFormat::Html,
+                Some(format) => return Err(format!("{}{}", INVALID_FORMAT, format)),
+                None => return Err(format!("{}{}", MISSING_VALUE, flag)),
+            };
+        } else if arguments.peek().is_none() && options.file.is_none() {
+            options.equation = Some(flag);
+        } else {
+            return Err(format!("{}{}", INVALID_OPTION, flag));
+        }
+    }
+    if options.equation.is_none() && options.file.is_none() {
+        return Err(INVALID_ARG_NUMBER.to_string());
+    }
+    Ok(options)
+}
+
+fn solve(polynomial: &mut Polynomial, options: &Options) -> Outcome {
+    if options.numerical {
+        polynomial.solve_numerically();
+    } else {
+        polynomial.solve();
+    }
+    match polynomial.solutions {
+        Some(SolutionSet::Unsupported { .. }) => Outcome::Unsolved,
+        _ => Outcome::Solved,
+    }
+}
+
+fn display_text(polynomial: &mut Polynomial, options: &Options) -> Outcome {
+    match options.command {
+        Command::Derive => display_polynomial::display_derivative(polynomial),
+        Command::Integrate => display_polynomial::display_antiderivative(polynomial),
+        Command::Solve => {
+            let outcome: Outcome = solve(polynomial, options);
+            display_polynomial::display_solved_polynomial(polynomial);
+            if options.verify {
+                display_polynomial::display_verification(polynomial);
+            }
+            if options.sturm {
+                display_polynomial::display_real_root_intervals(polynomial);
+            }
+            return outcome;
+        }
+    }
+    Outcome::Solved
+}
 
-    if args.len() != 2 {
-        handle_error(INVALID_ARG_NUMBER);
+fn to_json(equation: &str, polynomial: &mut Polynomial, options: &Options) -> (Json, Outcome) {
+    match options.command {
+        Command::Derive => (
+            polynomial_to_json(equation, &polynomial.derivative()),
+            Outcome::Solved,
+        ),
+        Command::Integrate => (
+            polynomial_to_json(equation, &polynomial.antiderivative()),
+            Outcome::Solved,
+        ),
+        Command::Solve => {
+            let outcome: Outcome = solve(polynomial, options);
+            (polynomial_to_json(equation, polynomial), outcome)
+        }
     }
+}
+
+fn to_latex(polynomial: &mut Polynomial, options: &Options) -> (String, Outcome) {
+    match options.command {
+        Command::Derive => (derivative_to_latex(polynomial), Outcome::Solved),
+        Command::Integrate => (antiderivative_to_latex(polynomial), Outcome::Solved),
+        Command::Solve => {
+            let outcome: Outcome = solve(polynomial, options);
+            (polynomial_to_latex(polynomial), outcome)
+        }
+    }
+}
+
+fn to_html(equation: &str, polynomial: &mut Polynomial, options: &Options) -> (String, Outcome) {
+    match options.command {
+        Command::Derive => (derivative_section(equation, polynomial), Outcome::Solved),
+        Command::Integrate => (
+            antiderivative_section(equation, polynomial),
+            Outcome::Solved,
+        ),
+        Command::Solve => {
+            let outcome: Outcome = solve(polynomial, options);
+            (polynomial_section(equation, polynomial), outcome)
+        }
+    }
+}
+
+/// Parse, run and print one equation, the line number being given in batch mode.
+fn run_equation(equation: &str, line: Option<usize>, options: &Options) -> Outcome {
+    let parsed = Polynomial::new_with_mode(equation, options.mode);
+
+    match options.format {
+        OutputFormat::Text => {
+            if let Some(line) = line {
+                println!("Line {}: {}", line, equation);
+            }
+            let outcome: Outcome = match parsed {
+                Ok(mut polynomial) => display_text(&mut polynomial, options),
+                Err(err) => {
+                    display_error::display_error(equation, &err);
+                    Outcome::InvalidEquation
+                }
+            };
+            if line.is_some() {
+                println!();
+            }
+            outcome
+        }
+        OutputFormat::Json => {
+            let (document, outcome): (Json, Outcome) = match parsed {
+                Ok(mut polynomial) => to_json(equation, &mut polynomial, options),
+                Err(err) => (error_to_json(equation, &err), Outcome::InvalidEquation),
+            };
+            match line {
+                Some(line) => println!("{}", document.with_field("line", Json::from(line))),
+                None => println!("{}", document),
+            }
+            outcome
+        }
+        OutputFormat::Latex => {
+            if let Some(line) = line {
+                println!("% Line {}: {}", line, equation);
+            }
+            let outcome: Outcome = match parsed {
+                Ok(mut polynomial) => {
+                    let (document, outcome): (String, Outcome) = to_latex(&mut polynomial, options);
+                    println!("{}", document);
+                    outcome
+                }
+                Err(err) => {
+                    println!("{}", error_to_latex(equation, &err));
+                    Outcome::InvalidEquation
+                }
+            };
+            if line.is_some() {
+                println!();
+            }
+            outcome
+        }
+        OutputFormat::Html => {
+            let (section, outcome): (String, Outcome) = match parsed {
+                Ok(mut polynomial) => to_html(equation, &mut polynomial, options),
+                Err(err) => (error_section(equation, &err), Outcome::InvalidEquation),
+            };
+            if let Some(line) = line {
+                println!("<!-- Line {} -->", line);
+            }
+            println!("{}", section);
+            outcome
+        }
+    }
+}
+
+fn open_file(path: &str) -> Box<dyn BufRead> {
+    if path == STDIN_PATH {
+        return Box::new(io::stdin().lock());
+    }
+    match File::open(path) {
+        Ok(file) => Box::new(BufReader::new(file)),
+        Err(err) => handle_error(&format!("{}{}: {}", UNREADABLE_FILE, path, err)),
+    }
+}
+
+fn display_summary(summary: &BatchSummary, options: &Options) {
+    match options.format {
+        OutputFormat::Text => println!("Summary: {}.", summary),
+        OutputFormat::Latex => println!("% Summary: {}.", summary),
+        OutputFormat::Html => println!("<p>Summary: {}.</p>", summary),
+        OutputFormat::Json => {
+            let failed: Vec<Json> = summary
+                .failed
+                .iter()
+                .map(|&line| Json::from(line))
+                .collect();
+            let summary: Json = Json::Object(vec![
+                ("solved".to_string(), Json::from(summary.solved)),
+                ("failed".to_string(), Json::Array(failed)),
+            ]);
+            println!("{}", Json::Object(vec![("summary".to_string(), summary)]));
+        }
+    }
+}
 
-    let equation: &String = &args[1];
-    let mut polynomial: Polynomial =
-        Polynomial::new(equation).unwrap_or_else(|err| handle_error(&err));
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    // Without an equation nor other option, `--natural` applies to the interactive mode.
+    if args[1..]
+        .iter()
+        .all(|arg| NATURAL_FLAGS.contains(&arg.as_str()))
+    {
+        let mode: ParseMode = match args.len() {
+            1 => ParseMode::Strict,
+            _ => ParseMode::Natural,
+        };
+        session::run(mode);
+        return;
+    }
 
-    polynomial.solve();
+    let options: Options = parse_arguments(&args).unwrap_or_else(|err| handle_error(&err));
 
-    display_polynomial::display_solved_polynomial(&polynomial);
+    // The equations of an HTML report are sections of a single page.
+    let html: bool = matches!(options.format, OutputFormat::Html);
+    if html {
+        println!("{}", HTML_HEAD);
+    }
+    let success: bool = match options.file {
+        Some(path) => {
+            let summary: BatchSummary = run_batch(open_file(path), |line, equation| {
+                run_equation(equation, Some(line), &options).is_success()
+            });
+            display_summary(&summary, &options);
+            summary.is_success()
+        }
+        None => {
+            let equation: &String = options.equation.expect("an equation or a file is required");
+            run_equation(equation, None, &options).is_success()
+        }
+    };
+    if html {
+        println!("{}", HTML_TAIL);
+    }
+    if !success {
+        process::exit(1);
+    }
 }
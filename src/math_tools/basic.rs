@@ -1,52 +1,124 @@
-use crate::constants::math_tools_constants::{FRACT_PRECISION, TOLERANCE};
+use crate::constants::math_tools_constants::{MAX_ITERATIONS, PI, SERIES_TOLERANCE};
 
 /// Compute the value of the square root using the Babylonian method (or Newton method).
 pub fn square_root(value: f64) -> f64 {
     if value < 0.0 {
         panic!("Error: square_root called with negative value.");
-    } else if value == 0.0 {
-        return 0.0;
+    } else if value == 0.0 || !value.is_finite() {
+        return value;
     }
 
-    let value: f64 = value;
-    let mut guess: f64 = value;
+    // Starting above the root, the guesses decrease until rounding stops them.
+    let mut guess: f64 = value.max(1.0);
 
     loop {
         let next_guess: f64 = (guess + value / guess) / 2.0;
 
-        if (next_guess - guess).abs() < TOLERANCE {
-            return next_guess;
+        if next_guess >= guess {
+            return guess;
         }
         guess = next_guess;
     }
 }
 
-pub fn gcd(a: i64, b: i64) -> i64 {
-    if b == 0 {
-        a
-    } else {
-        gcd(b, a % b)
+/// Real cube root, using Newton's method on `x^3 - value`.
+pub fn cube_root(value: f64) -> f64 {
+    if value < 0.0 {
+        return -cube_root(-value);
+    } else if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+
+    let mut guess: f64 = value.max(1.0);
+
+    for _ in 0..MAX_ITERATIONS {
+        let next_guess: f64 = (2.0 * guess + value / (guess * guess)) / 3.0;
+
+        if next_guess >= guess {
+            return guess;
+        }
+        guess = next_guess;
+    }
+    guess
+}
+
+/// Positive `n`-th root of a non-negative value, using Newton's method on `x^n - value`.
+pub fn nth_root(value: f64, n: u32) -> f64 {
+    if value < 0.0 {
+        panic!("Error: nth_root called with negative value.");
+    } else if value == 0.0 || !value.is_finite() || n == 1 {
+        return value;
+    }
+
+    let mut guess: f64 = value.max(1.0);
+
+    for _ in 0..MAX_ITERATIONS {
+        let next_guess: f64 =
+            ((n - 1) as f64 * guess + value / guess.powi(n as i32 - 1)) / n as f64;
+
+        if next_guess >= guess {
+            return guess;
+        }
+        guess = next_guess;
     }
+    guess
 }
 
-pub fn convert_to_irreducible(value: f64) -> Option<(i64, i64)> {
-    if value.fract() == 0.0 {
-        return None;
+/// Cosine from its Taylor series, after reducing `angle` to `[-π, π]`.
+pub fn cosine(angle: f64) -> f64 {
+    let turns: f64 = (angle / (2.0 * PI)).round();
+    let angle: f64 = angle - turns * 2.0 * PI;
+
+    let mut term: f64 = 1.0;
+    let mut sum: f64 = 1.0;
+    let mut n: f64 = 0.0;
+
+    while term.abs() > SERIES_TOLERANCE {
+        n += 2.0;
+        term *= -angle * angle / ((n - 1.0) * n);
+        sum += term;
     }
+    sum
+}
 
-    let (numerator, denominator): (i64, i64) = (
-        (value * FRACT_PRECISION as f64).round() as i64,
-        FRACT_PRECISION,
-    );
+pub fn sine(angle: f64) -> f64 {
+    cosine(angle - PI / 2.0)
+}
 
-    let divisor: i64 = gcd(numerator, denominator);
+/// Arctangent from its Taylor series, after halving the angle until the series
+/// converges quickly.
+pub fn arc_tangent(value: f64) -> f64 {
+    if value < 0.0 {
+        return -arc_tangent(-value);
+    } else if value.is_infinite() {
+        return PI / 2.0;
+    } else if value > 1.0 {
+        return PI / 2.0 - arc_tangent(1.0 / value);
+    }
 
-    let (reduced_numerator, reduced_denominator): (i64, i64) =
-        (numerator / divisor, denominator / divisor);
+    // atan(x) = 2 * atan(x / (1 + √(1 + x²)))
+    let mut value: f64 = value;
+    let mut doublings: i32 = 0;
+    while value > 0.1 {
+        value /= 1.0 + square_root(1.0 + value * value);
+        doublings += 1;
+    }
 
-    if reduced_denominator < 0 {
-        return Some((-reduced_numerator, -reduced_denominator));
+    let mut power: f64 = value;
+    let mut sum: f64 = 0.0;
+    let mut n: f64 = 1.0;
+    while power.abs() / n > SERIES_TOLERANCE {
+        sum += power / n;
+        power *= -value * value;
+        n += 2.0;
     }
+    sum * 2f64.powi(doublings)
+}
 
-    Some((reduced_numerator, reduced_denominator))
+pub fn arc_cosine(value: f64) -> f64 {
+    if !(-1.0..=1.0).contains(&value) {
+        panic!("Error: arc_cosine called with a value outside [-1, 1].");
+    }
+    // acos(x) = 2 * atan(√((1 - x) / (1 + x)))
+    2.0 * arc_tangent(square_root((1.0 - value) / (1.0 + value)))
 }
@@ -0,0 +1,524 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+const LIMB_BITS: u32 = 32;
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+/// Arbitrary-precision signed integer, stored as little-endian base 2^32 limbs
+/// without trailing zeros. Zero has no limbs and is never negative.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl BigInt {
+    pub fn zero() -> Self {
+        BigInt::default()
+    }
+
+    pub fn one() -> Self {
+        BigInt::from(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        !self.negative && self.magnitude == [1]
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.magnitude.first().is_none_or(|limb| limb % 2 == 0)
+    }
+
+    pub fn abs(&self) -> Self {
+        BigInt::from_parts(false, self.magnitude.clone())
+    }
+
+    /// Number of significant bits of the absolute value.
+    pub fn bits(&self) -> u64 {
+        match self.magnitude.last() {
+            Some(last) => {
+                (self.magnitude.len() as u64) * LIMB_BITS as u64 - last.leading_zeros() as u64
+            }
+            None => 0,
+        }
+    }
+
+    pub fn pow(&self, exponent: u32) -> Self {
+        let mut result: BigInt = BigInt::one();
+        let mut base: BigInt = self.clone();
+        let mut exponent: u32 = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Quotient rounded toward zero and remainder of the sign of `self`.
+    pub fn div_rem(&self, divisor: &BigInt) -> (BigInt, BigInt) {
+        if divisor.is_zero() {
+            panic!("Error: BigInt division by zero.");
+        }
+        let (quotient, remainder) = div_rem_magnitude(&self.magnitude, &divisor.magnitude);
+
+        (
+            BigInt::from_parts(self.negative != divisor.negative, quotient),
+            BigInt::from_parts(self.negative, remainder),
+        )
+    }
+
+    /// Greatest common divisor, always non-negative.
+    pub fn gcd(&self, other: &BigInt) -> BigInt {
+        let mut a: BigInt = self.abs();
+        let mut b: BigInt = other.abs();
+
+        while !b.is_zero() {
+            let remainder: BigInt = a.div_rem(&b).1;
+            a = b;
+            b = remainder;
+        }
+        a
+    }
+
+    /// Least common multiple of two non-zero values, with the sign of their product.
+    pub fn lcm(&self, other: &BigInt) -> BigInt {
+        &(self / &self.gcd(other)) * other
+    }
+
+    /// Integer square root, rounded down.
+    pub fn sqrt(&self) -> BigInt {
+        if self.negative {
+            panic!("Error: BigInt::sqrt called with negative value.");
+        } else if self.is_zero() {
+            return BigInt::zero();
+        }
+
+        let mut guess: BigInt = BigInt::one().shl(self.bits().div_ceil(2));
+        loop {
+            let next_guess: BigInt = (&guess + &(self / &guess)).shr(1);
+            if next_guess >= guess {
+                return guess;
+            }
+            guess = next_guess;
+        }
+    }
+
+    pub fn shl(&self, shift: u64) -> BigInt {
+        let limbs: usize = (shift / LIMB_BITS as u64) as usize;
+        let mut magnitude: Vec<u32> = vec![0; limbs];
+        magnitude.extend(shl_bits(&self.magnitude, (shift % LIMB_BITS as u64) as u32));
+        BigInt::from_parts(self.negative, magnitude)
+    }
+
+    /// Shift of the absolute value, the sign is kept.
+    pub fn shr(&self, shift: u64) -> BigInt {
+        let limbs: usize = (shift / LIMB_BITS as u64) as usize;
+        if limbs >= self.magnitude.len() {
+            return BigInt::zero();
+        }
+        let magnitude: Vec<u32> =
+            shr_bits(&self.magnitude[limbs..], (shift % LIMB_BITS as u64) as u32);
+        BigInt::from_parts(self.negative, magnitude)
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let value: f64 = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0.0, |value, &limb| value * 4_294_967_296.0 + limb as f64);
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.magnitude.len() > 2 {
+            return None;
+        }
+        let magnitude: u64 = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0u64, |value, &limb| (value << LIMB_BITS) | limb as u64);
+        if self.negative {
+            0i64.checked_sub_unsigned(magnitude)
+        } else {
+            i64::try_from(magnitude).ok()
+        }
+    }
+
+    /// Private part
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        BigInt {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    fn add_signed(&self, other: &BigInt, other_negative: bool) -> BigInt {
+        if self.negative == other_negative {
+            return BigInt::from_parts(
+                self.negative,
+                add_magnitude(&self.magnitude, &other.magnitude),
+            );
+        }
+        match compare_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::from_parts(
+                other_negative,
+                sub_magnitude(&other.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::from_parts(
+                self.negative,
+                sub_magnitude(&self.magnitude, &other.magnitude),
+            ),
+        }
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        let mut result: BigInt = BigInt::from(value.unsigned_abs());
+        result.negative = value < 0;
+        result
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(value: u64) -> Self {
+        BigInt::from_parts(false, vec![value as u32, (value >> LIMB_BITS) as u32])
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(value: i32) -> Self {
+        BigInt::from(value as i64)
+    }
+}
+
+impl From<usize> for BigInt {
+    fn from(value: usize) -> Self {
+        BigInt::from(value as u64)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    /// Decimal digits, optionally preceded by a sign.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (negative, digits): (bool, &str) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+        if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+
+        let mut magnitude: Vec<u32> = Vec::new();
+        for chunk in digits.as_bytes().chunks(DECIMAL_CHUNK_DIGITS) {
+            let value: u32 = chunk
+                .iter()
+                .fold(0, |value, digit| value * 10 + (digit - b'0') as u32);
+            mul_add_small(&mut magnitude, 10u32.pow(chunk.len() as u32), value);
+        }
+        Ok(BigInt::from_parts(negative, magnitude))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let mut chunks: Vec<u32> = Vec::new();
+        let mut magnitude: Vec<u32> = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = div_rem_small(&magnitude, DECIMAL_CHUNK);
+            chunks.push(remainder);
+            magnitude = quotient;
+        }
+
+        let mut text: String = if self.negative {
+            "-".to_string()
+        } else {
+            String::new()
+        };
+        for (index, chunk) in chunks.iter().rev().enumerate() {
+            if index == 0 {
+                text.push_str(&chunk.to_string());
+            } else {
+                text.push_str(&format!("{:0width$}", chunk, width = DECIMAL_CHUNK_DIGITS));
+            }
+        }
+        f.pad(&text)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+fn add(lhs: &BigInt, rhs: &BigInt) -> BigInt {
+    lhs.add_signed(rhs, rhs.negative)
+}
+
+fn sub(lhs: &BigInt, rhs: &BigInt) -> BigInt {
+    lhs.add_signed(rhs, !rhs.negative)
+}
+
+fn mul(lhs: &BigInt, rhs: &BigInt) -> BigInt {
+    BigInt::from_parts(
+        lhs.negative != rhs.negative,
+        mul_magnitude(&lhs.magnitude, &rhs.magnitude),
+    )
+}
+
+fn div(lhs: &BigInt, rhs: &BigInt) -> BigInt {
+    lhs.div_rem(rhs).0
+}
+
+fn rem(lhs: &BigInt, rhs: &BigInt) -> BigInt {
+    lhs.div_rem(rhs).1
+}
+
+forward_binop!(BigInt, Add, add, add);
+forward_binop!(BigInt, Sub, sub, sub);
+forward_binop!(BigInt, Mul, mul, mul);
+forward_binop!(BigInt, Div, div, div);
+forward_binop!(BigInt, Rem, rem, rem);
+
+fn compare_magnitude(lhs: &[u32], rhs: &[u32]) -> Ordering {
+    lhs.len()
+        .cmp(&rhs.len())
+        .then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+}
+
+fn add_magnitude(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let (long, short) = if lhs.len() >= rhs.len() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+    let mut result: Vec<u32> = Vec::with_capacity(long.len() + 1);
+    let mut carry: u64 = 0;
+
+    for (index, &limb) in long.iter().enumerate() {
+        let sum: u64 = limb as u64 + short.get(index).copied().unwrap_or(0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> LIMB_BITS;
+    }
+    result.push(carry as u32);
+    result
+}
+
+/// `lhs - rhs`, with `lhs >= rhs`.
+fn sub_magnitude(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result: Vec<u32> = Vec::with_capacity(lhs.len());
+    let mut borrow: i64 = 0;
+
+    for (index, &limb) in lhs.iter().enumerate() {
+        let difference: i64 = limb as i64 - rhs.get(index).copied().unwrap_or(0) as i64 - borrow;
+        result.push(difference as u32);
+        borrow = (difference < 0) as i64;
+    }
+    result
+}
+
+fn mul_magnitude(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return Vec::new();
+    }
+    let mut result: Vec<u32> = vec![0; lhs.len() + rhs.len()];
+
+    for (i, &a) in lhs.iter().enumerate() {
+        let mut carry: u64 = 0;
+        for (j, &b) in rhs.iter().enumerate() {
+            let product: u64 = a as u64 * b as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> LIMB_BITS;
+        }
+        result[i + rhs.len()] = carry as u32;
+    }
+    result
+}
+
+fn mul_add_small(magnitude: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry: u64 = addend as u64;
+
+    for limb in magnitude.iter_mut() {
+        let value: u64 = *limb as u64 * factor as u64 + carry;
+        *limb = value as u32;
+        carry = value >> LIMB_BITS;
+    }
+    if carry > 0 {
+        magnitude.push(carry as u32);
+    }
+}
+
+fn div_rem_small(magnitude: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient: Vec<u32> = vec![0; magnitude.len()];
+    let mut remainder: u64 = 0;
+
+    for (index, &limb) in magnitude.iter().enumerate().rev() {
+        let value: u64 = (remainder << LIMB_BITS) | limb as u64;
+        quotient[index] = (value / divisor as u64) as u32;
+        remainder = value % divisor as u64;
+    }
+    while quotient.last() == Some(&0) {
+        quotient.pop();
+    }
+    (quotient, remainder as u32)
+}
+
+fn shl_bits(magnitude: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return magnitude.to_vec();
+    }
+    let mut result: Vec<u32> = Vec::with_capacity(magnitude.len() + 1);
+    let mut carry: u32 = 0;
+
+    for &limb in magnitude {
+        result.push((limb << shift) | carry);
+        carry = limb >> (LIMB_BITS - shift);
+    }
+    result.push(carry);
+    result
+}
+
+fn shr_bits(magnitude: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return magnitude.to_vec();
+    }
+    (0..magnitude.len())
+        .map(|index| {
+            let high: u32 = magnitude
+                .get(index + 1)
+                .map_or(0, |&limb| limb << (LIMB_BITS - shift));
+            (magnitude[index] >> shift) | high
+        })
+        .collect()
+}
+
+/// Schoolbook long division (Knuth, TAOCP vol. 2, algorithm D).
+fn div_rem_magnitude(dividend: &[u32], divisor: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare_magnitude(dividend, divisor) == Ordering::Less {
+        return (Vec::new(), dividend.to_vec());
+    }
+    if divisor.len() == 1 {
+        let (quotient, remainder) = div_rem_small(dividend, divisor[0]);
+        return (quotient, vec![remainder]);
+    }
+
+    let shift: u32 = divisor[divisor.len() - 1].leading_zeros();
+    let divisor: Vec<u32> = shl_bits(divisor, shift)[..divisor.len()].to_vec();
+    let mut remainder: Vec<u32> = shl_bits(dividend, shift);
+    if remainder.len() == dividend.len() {
+        remainder.push(0);
+    }
+
+    let n: usize = divisor.len();
+    let m: usize = remainder.len() - n - 1;
+    let mut quotient: Vec<u32> = vec![0; m + 1];
+    let base: u128 = 1 << LIMB_BITS;
+
+    for j in (0..=m).rev() {
+        let numerator: u128 =
+            ((remainder[j + n] as u128) << LIMB_BITS) | remainder[j + n - 1] as u128;
+        let mut estimate: u128 = numerator / divisor[n - 1] as u128;
+        let mut estimate_remainder: u128 = numerator % divisor[n - 1] as u128;
+
+        while estimate >= base
+            || estimate * divisor[n - 2] as u128
+                > ((estimate_remainder << LIMB_BITS) | remainder[j + n - 2] as u128)
+        {
+            estimate -= 1;
+            estimate_remainder += divisor[n - 1] as u128;
+            if estimate_remainder >= base {
+                break;
+            }
+        }
+
+        let mut borrow: i64 = 0;
+        let mut carry: u64 = 0;
+        for i in 0..n {
+            let product: u64 = estimate as u64 * divisor[i] as u64 + carry;
+            carry = product >> LIMB_BITS;
+            let difference: i64 = remainder[i + j] as i64 - borrow - (product as u32) as i64;
+            remainder[i + j] = difference as u32;
+            borrow = (difference < 0) as i64;
+        }
+        let difference: i64 = remainder[j + n] as i64 - borrow - carry as i64;
+        remainder[j + n] = difference as u32;
+
+        if difference < 0 {
+            estimate -= 1;
+            let mut carry: u64 = 0;
+            for i in 0..n {
+                let sum: u64 = remainder[i + j] as u64 + divisor[i] as u64 + carry;
+                remainder[i + j] = sum as u32;
+                carry = sum >> LIMB_BITS;
+            }
+            remainder[j + n] = remainder[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = estimate as u32;
+    }
+
+    (quotient, shr_bits(&remainder[..n], shift))
+}
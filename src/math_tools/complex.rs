@@ -0,0 +1,104 @@
+use crate::math_tools::basic;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Complex value `real + i * imaginary`, used for the non-real roots of a polynomial.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex {
+    pub real: f64,
+    pub imaginary: f64,
+}
+
+impl Complex {
+    pub fn new(real: f64, imaginary: f64) -> Self {
+        Complex { real, imaginary }
+    }
+
+    pub fn zero() -> Self {
+        Complex::new(0.0, 0.0)
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Complex::new(self.real, -self.imaginary)
+    }
+
+    pub fn modulus(&self) -> f64 {
+        basic::square_root(self.real * self.real + self.imaginary * self.imaginary)
+    }
+
+    /// Principal square root, the one with a non-negative real part.
+    pub fn sqrt(&self) -> Self {
+        let modulus: f64 = self.modulus();
+        let real: f64 = basic::square_root((modulus + self.real).max(0.0) / 2.0);
+        let imaginary: f64 = basic::square_root((modulus - self.real).max(0.0) / 2.0);
+
+        if self.imaginary < 0.0 {
+            Complex::new(real, -imaginary)
+        } else {
+            Complex::new(real, imaginary)
+        }
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(value: f64) -> Self {
+        Complex::new(value, 0.0)
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.real, -self.imaginary)
+    }
+}
+
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.imaginary < 0.0 {
+            write!(f, "{} - i * {}", self.real, -self.imaginary)
+        } else {
+            write!(f, "{} + i * {}", self.real, self.imaginary)
+        }
+    }
+}
+
+fn add(lhs: &Complex, rhs: &Complex) -> Complex {
+    Complex::new(lhs.real + rhs.real, lhs.imaginary + rhs.imaginary)
+}
+
+fn sub(lhs: &Complex, rhs: &Complex) -> Complex {
+    Complex::new(lhs.real - rhs.real, lhs.imaginary - rhs.imaginary)
+}
+
+fn mul(lhs: &Complex, rhs: &Complex) -> Complex {
+    Complex::new(
+        lhs.real * rhs.real - lhs.imaginary * rhs.imaginary,
+        lhs.real * rhs.imaginary + lhs.imaginary * rhs.real,
+    )
+}
+
+/// Smith's algorithm, which scales by the larger part of `rhs` to avoid overflows.
+fn div(lhs: &Complex, rhs: &Complex) -> Complex {
+    if rhs.real.abs() >= rhs.imaginary.abs() {
+        let ratio: f64 = rhs.imaginary / rhs.real;
+        let denominator: f64 = rhs.real + rhs.imaginary * ratio;
+        Complex::new(
+            (lhs.real + lhs.imaginary * ratio) / denominator,
+            (lhs.imaginary - lhs.real * ratio) / denominator,
+        )
+    } else {
+        let ratio: f64 = rhs.real / rhs.imaginary;
+        let denominator: f64 = rhs.real * ratio + rhs.imaginary;
+        Complex::new(
+            (lhs.real * ratio + lhs.imaginary) / denominator,
+            (lhs.imaginary * ratio - lhs.real) / denominator,
+        )
+    }
+}
+
+forward_binop!(Complex, Add, add, add);
+forward_binop!(Complex, Sub, sub, sub);
+forward_binop!(Complex, Mul, mul, mul);
+forward_binop!(Complex, Div, div, div);
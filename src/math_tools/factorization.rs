@@ -0,0 +1,248 @@
+use crate::constants::math_tools_constants::{FACTORING_CANDIDATE_LIMIT, FACTORING_DIVISOR_LIMIT};
+use crate::math_tools::big_int::BigInt;
+use crate::math_tools::polynomial::Polynomial;
+use crate::math_tools::rational::Rational;
+use std::fmt;
+
+/// `constant * Π(X - root)^multiplicity * remaining`, over the rationals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Factorization {
+    pub constant: Rational,
+    /// Rational roots in increasing order, with their multiplicities.
+    pub roots: Vec<(Rational, usize)>,
+    /// Monic factor without rational root, `1` when every root is rational.
+    pub remaining: Polynomial,
+}
+
+/// Rational roots from the rational root theorem, each one deflated by exact division
+/// as many times as it divides the polynomial. The roots are only searched when both
+/// extreme coefficients are below `FACTORING_DIVISOR_LIMIT` and give at most
+/// `FACTORING_CANDIDATE_LIMIT` candidates, the polynomial being left in the remaining
+/// factor otherwise.
+pub fn factorize(polynomial: &Polynomial) -> Factorization {
+    let constant: Rational = polynomial.leading_coefficient().clone();
+    if polynomial.is_zero() {
+        return Factorization {
+            constant,
+            roots: Vec::new(),
+            remaining: Polynomial::from_coefficients(&[Rational::one()]),
+        };
+    }
+
+    let monic: Polynomial = polynomial.scale(&constant.recip());
+    let zeros: usize = monic
+        .coefficients
+        .iter()
+        .take_while(|c| c.is_zero())
+        .count();
+    let reduced: Polynomial = Polynomial::from_coefficients(&monic.coefficients[zeros..]);
+    let mut remaining: Polynomial = reduced.clone();
+    let mut roots: Vec<(Rational, usize)> = Vec::new();
+    if zeros > 0 {
+        roots.push((Rational::zero(), zeros));
+    }
+
+    for candidate in rational_root_candidates(&reduced) {
+        if remaining.degree == 0 {
+            break;
+        }
+        let linear: Polynomial = Polynomial::from_coefficients(&[-&candidate, Rational::one()]);
+        let mut multiplicity: usize = 0;
+        while remaining.degree > 0 && remaining.evaluate_rational(&candidate).is_zero() {
+            remaining = remaining.div_rem(&linear).0;
+            multiplicity += 1;
+        }
+        if multiplicity > 0 {
+            roots.push((candidate, multiplicity));
+        }
+    }
+    roots.sort_by(|a, b| a.0.cmp(&b.0));
+
+    Factorization {
+        constant,
+        roots,
+        remaining,
+    }
+}
+
+/// Yun's algorithm: monic square-free factors `A_i`, pairwise coprime, with
+/// `polynomial = constant * Π A_i^i`, each paired with its multiplicity `i`. The roots of
+/// `A_i` are exactly the roots of multiplicity `i`.
+pub fn square_free_factors(polynomial: &Polynomial) -> Vec<(Polynomial, usize)> {
+    if polynomial.degree == 0 {
+        return Vec::new();
+    }
+    let polynomial: Polynomial = polynomial.monic();
+    let derivative: Polynomial = polynomial.derivative();
+    let common: Polynomial = polynomial.gcd(&derivative);
+
+    let mut remaining: Polynomial = polynomial.div_rem(&common).0;
+    let mut difference: Polynomial = derivative.div_rem(&common).0 - remaining.derivative();
+    let mut factors: Vec<(Polynomial, usize)> = Vec::new();
+    let mut multiplicity: usize = 1;
+
+    while remaining.degree > 0 {
+        let factor: Polynomial = remaining.gcd(&difference);
+        remaining = remaining.div_rem(&factor).0;
+        difference = difference.div_rem(&factor).0 - remaining.derivative();
+        if factor.degree > 0 {
+            factors.push((factor, multiplicity));
+        }
+        multiplicity += 1;
+    }
+    factors
+}
+
+/// Terms by decreasing power, as in `X^3 - 2*X + 1/2`.
+pub fn format_descending(polynomial: &Polynomial) -> String {
+    let mut terms: Vec<String> = Vec::new();
+    for (power, coefficient) in polynomial.coefficients.iter().enumerate().rev() {
+        if coefficient.is_zero() {
+            continue;
+        }
+        let magnitude: String = coefficient.abs().to_fraction();
+        let term: String = match (power, magnitude.as_str()) {
+            (0, _) => magnitude,
+            (1, "1") => "X".to_string(),
+            (1, _) => format!("{}*X", magnitude),
+            (_, "1") => format!("X^{}", power),
+            (_, _) => format!("{}*X^{}", magnitude, power),
+        };
+        let sign: &str = match (terms.is_empty(), coefficient.is_negative()) {
+            (true, true) => "-",
+            (true, false) => "",
+            (false, true) => " - ",
+            (false, false) => " + ",
+        };
+        terms.push(format!("{}{}", sign, term));
+    }
+    if terms.is_empty() {
+        terms.push("0".to_string());
+    }
+    terms.concat()
+}
+
+impl fmt::Display for Factorization {
+    /// `2(X - 1/2)(X + 3)(X^2 + 1)`, or `2X^2(X^2 + 1)` with a zero root.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut factors: Vec<String> = Vec::new();
+        // The power of X comes first, as in `X(X - 1)` rather than `(X + 1)X`.
+        let (zero, others): (Vec<_>, Vec<_>) =
+            self.roots.iter().partition(|(root, _)| root.is_zero());
+        for (root, multiplicity) in zero.into_iter().chain(others) {
+            let factor: String = if root.is_zero() {
+                "X".to_string()
+            } else if root.is_negative() {
+                format!("(X + {})", (-root).to_fraction())
+            } else {
+                format!("(X - {})", root.to_fraction())
+            };
+            factors.push(match multiplicity {
+                1 => factor,
+                _ => format!("{}^{}", factor, multiplicity),
+            });
+        }
+        if self.remaining.degree > 0 {
+            let remaining: String = format_descending(&self.remaining);
+            if factors.is_empty() && self.constant == Rational::one() {
+                return write!(f, "{}", remaining);
+            }
+            factors.push(format!("({})", remaining));
+        }
+
+        let constant: String = if factors.is_empty() {
+            self.constant.to_fraction()
+        } else if self.constant == Rational::one() {
+            String::new()
+        } else if self.constant == Rational::from(-1) {
+            "-".to_string()
+        } else {
+            self.constant.to_fraction()
+        };
+        write!(f, "{}{}", constant, factors.join(""))
+    }
+}
+
+// Private part
+/// Candidates `±p / q` with `p` dividing the constant term and `q` the leading coefficient
+/// once the coefficients are scaled to coprime integers, generated lazily. Those beyond
+/// Cauchy's bound `1 + max |a_i / a_n|` are skipped, as well as those failing the
+/// divisibility test: `q - p` divides `P(1)` and `q + p` divides `P(-1)`.
+fn rational_root_candidates(polynomial: &Polynomial) -> impl Iterator<Item = Rational> + '_ {
+    let mut pairs: Vec<(u64, u64)> = Vec::new();
+    let mut integers: Vec<BigInt> = Vec::new();
+
+    if polynomial.degree > 0 && !polynomial.coefficients[0].is_zero() {
+        let scale: BigInt = polynomial
+            .coefficients
+            .iter()
+            .fold(BigInt::one(), |scale, c| scale.lcm(c.denominator()));
+        integers = polynomial
+            .coefficients
+            .iter()
+            .map(|c| c.numerator() * &(&scale / c.denominator()))
+            .collect();
+
+        if let (Some(numerators), Some(denominators)) = (
+            divisors(&integers[0]),
+            divisors(&integers[integers.len() - 1]),
+        ) {
+            if numerators.len() * denominators.len() <= FACTORING_CANDIDATE_LIMIT {
+                for &p in &numerators {
+                    pairs.extend(
+                        denominators
+                            .iter()
+                            .filter(|&&q| BigInt::from(p).gcd(&BigInt::from(q)).is_one())
+                            .map(|&q| (p, q)),
+                    );
+                }
+            }
+        }
+    }
+
+    let at_one: BigInt = integers.iter().fold(BigInt::zero(), |sum, c| sum + c);
+    let at_minus_one: BigInt =
+        integers
+            .iter()
+            .enumerate()
+            .fold(BigInt::zero(), |sum, (power, c)| match power % 2 {
+                0 => sum + c,
+                _ => sum - c,
+            });
+    let bound: Rational = polynomial.root_bound();
+
+    pairs
+        .into_iter()
+        .flat_map(|(p, q)| [(-(p as i64), q as i64), (p as i64, q as i64)])
+        .filter(move |&(p, q)| divides(q - p, &at_one) && divides(q + p, &at_minus_one))
+        .map(|(p, q)| Rational::new(BigInt::from(p), BigInt::from(q)))
+        .filter(move |candidate| candidate.abs() <= bound)
+}
+
+/// Whether `divisor` divides `value`, a zero divisor only dividing zero.
+fn divides(divisor: i64, value: &BigInt) -> bool {
+    if divisor == 0 {
+        return value.is_zero();
+    }
+    (value % &BigInt::from(divisor)).is_zero()
+}
+
+/// Positive divisors of `value`, `None` above `FACTORING_DIVISOR_LIMIT`.
+fn divisors(value: &BigInt) -> Option<Vec<u64>> {
+    let value: u64 = value.abs().to_i64()? as u64;
+    if value > FACTORING_DIVISOR_LIMIT {
+        return None;
+    }
+    let mut divisors: Vec<u64> = Vec::new();
+    let mut divisor: u64 = 1;
+    while divisor * divisor <= value {
+        if value.is_multiple_of(divisor) {
+            divisors.push(divisor);
+            if divisor * divisor != value {
+                divisors.push(value / divisor);
+            }
+        }
+        divisor += 1;
+    }
+    Some(divisors)
+}
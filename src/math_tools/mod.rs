@@ -1,2 +1,49 @@
+/// Implement an operator trait for every combination of owned and borrowed operands,
+/// all forwarding to `$function(&lhs, &rhs)`.
+macro_rules! forward_binop {
+    ($type:ty, $trait:ident, $method:ident, $function:ident) => {
+        impl $trait<&$type> for &$type {
+            type Output = $type;
+
+            fn $method(self, rhs: &$type) -> $type {
+                $function(self, rhs)
+            }
+        }
+
+        impl $trait<$type> for $type {
+            type Output = $type;
+
+            fn $method(self, rhs: $type) -> $type {
+                $function(&self, &rhs)
+            }
+        }
+
+        impl $trait<&$type> for $type {
+            type Output = $type;
+
+            fn $method(self, rhs: &$type) -> $type {
+                $function(&self, rhs)
+            }
+        }
+
+        impl $trait<$type> for &$type {
+            type Output = $type;
+
+            fn $method(self, rhs: $type) -> $type {
+                $function(self, &rhs)
+            }
+        }
+    };
+}
+
 pub mod basic;
+pub mod big_int;
+pub mod complex;
+pub mod factorization;
 pub mod polynomial;
+pub mod rational;
+pub mod root;
+pub mod solution;
+pub mod sturm;
+pub mod surd;
+pub mod verification;
@@ -1,32 +1,145 @@
+use crate::constants::math_tools_constants::NO_SOLVER;
+use crate::errors::ComputorError;
+use crate::math_tools::complex::Complex;
+use crate::math_tools::rational::Rational;
+use crate::math_tools::solution::SolutionSet;
+use crate::math_tools::sturm;
 use crate::parser;
-use crate::solvers::linear::solve_linear;
-use crate::solvers::quadratic::solve_quadratic;
+use crate::parser::ParseMode;
+use crate::solvers::derivation::{Derivation, Step};
+use crate::solvers::registry::SolverRegistry;
+use std::ops::{Add, Mul, Neg, Sub};
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct Polynomial {
     pub degree: usize,
-    pub coefficients: Vec<f64>,
-    pub solutions: Option<Vec<f64>>,
-    pub discriminant: f64,
+    pub coefficients: Vec<Rational>,
+    pub solutions: Option<SolutionSet>,
+    pub steps: Vec<Step>,
 }
 
 impl Polynomial {
-    pub fn new(equation: &str) -> Result<Self, String> {
-        let mut coefficients: Vec<f64> = parser::parse_input(equation)?;
+    pub fn new(equation: &str) -> Result<Self, ComputorError> {
+        Self::new_with_mode(equation, ParseMode::Strict)
+    }
+
+    pub fn new_with_mode(equation: &str, mode: ParseMode) -> Result<Self, ComputorError> {
+        let coefficients: Vec<Rational> = parser::parse_input_with_mode(equation, mode)?;
+        Ok(Self::from_coefficients(&coefficients))
+    }
+
+    /// Polynomial `Σ coefficients[i] * X^i`, the zero polynomial for an empty slice.
+    pub fn from_coefficients(coefficients: &[Rational]) -> Self {
+        let mut coefficients: Vec<Rational> = coefficients.to_vec();
+        if coefficients.is_empty() {
+            coefficients.push(Rational::zero());
+        }
         let degree: usize = Self::get_polynomial_degree(&mut coefficients);
 
-        Ok(Polynomial {
+        Polynomial {
             degree,
             coefficients,
             solutions: None,
-            discriminant: 0.0,
-        })
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::from_coefficients(&[Rational::zero()])
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.degree == 0 && self.coefficients[0].is_zero()
+    }
+
+    pub fn leading_coefficient(&self) -> &Rational {
+        &self.coefficients[self.degree]
+    }
+
+    /// Product with a constant.
+    pub fn scale(&self, factor: &Rational) -> Self {
+        let coefficients: Vec<Rational> = self.coefficients.iter().map(|c| c * factor).collect();
+        Self::from_coefficients(&coefficients)
+    }
+
+    /// Monic greatest common divisor from Euclid's algorithm, zero when both are zero.
+    pub fn gcd(&self, other: &Polynomial) -> Self {
+        let (mut a, mut b): (Polynomial, Polynomial) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let remainder: Polynomial = a.div_rem(&b).1;
+            (a, b) = (b, remainder);
+        }
+        a.monic()
+    }
+
+    /// Same roots with a leading coefficient of 1, unless zero.
+    pub fn monic(&self) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+        self.scale(&self.leading_coefficient().recip())
     }
 
-    pub fn get_polynomial_degree(coefficients: &mut Vec<f64>) -> usize {
+    /// `d/dX`, the zero polynomial for a constant.
+    pub fn derivative(&self) -> Self {
+        let coefficients: Vec<Rational> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(power, coefficient)| coefficient * Rational::from(power as i64))
+            .collect();
+        Self::from_coefficients(&coefficients)
+    }
+
+    /// `∫ dX`, with a zero constant of integration.
+    pub fn antiderivative(&self) -> Self {
+        let mut coefficients: Vec<Rational> = vec![Rational::zero()];
+        coefficients.extend(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .map(|(power, coefficient)| coefficient / Rational::from(power as i64 + 1)),
+        );
+        Self::from_coefficients(&coefficients)
+    }
+
+    /// Euclidean division, giving `(quotient, remainder)` with `self = quotient * divisor +
+    /// remainder` and the remainder of degree lower than the divisor, or zero.
+    pub fn div_rem(&self, divisor: &Polynomial) -> (Polynomial, Polynomial) {
+        if divisor.is_zero() {
+            panic!("Error: Polynomial division by zero.");
+        }
+        if self.degree < divisor.degree {
+            return (Self::zero(), Self::from_coefficients(&self.coefficients));
+        }
+
+        let mut remainder: Vec<Rational> = self.coefficients.clone();
+        let mut quotient: Vec<Rational> = vec![Rational::zero(); self.degree - divisor.degree + 1];
+        let leading: &Rational = divisor.leading_coefficient();
+
+        for shift in (0..quotient.len()).rev() {
+            let factor: Rational = &remainder[shift + divisor.degree] / leading;
+            if factor.is_zero() {
+                continue;
+            }
+            for (i, coefficient) in divisor.coefficients.iter().enumerate() {
+                remainder[shift + i] -= &(coefficient * &factor);
+            }
+            quotient[shift] = factor;
+        }
+        remainder.truncate(divisor.degree.max(1));
+        (
+            Self::from_coefficients(&quotient),
+            Self::from_coefficients(&remainder),
+        )
+    }
+
+    pub fn get_polynomial_degree(coefficients: &mut Vec<Rational>) -> usize {
         let mut degree: usize = coefficients.len() - 1;
 
         while degree > 0 {
-            if coefficients[degree] == 0.0 {
+            if coefficients[degree].is_zero() {
                 degree -= 1;
                 coefficients.pop();
             } else {
@@ -36,15 +149,157 @@ impl Polynomial {
         degree
     }
 
+    /// Value at `x` with Horner's scheme.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |value, coefficient| value * x + coefficient.to_f64())
+    }
+
+    pub fn evaluate_complex(&self, z: Complex) -> Complex {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Complex::zero(), |value, coefficient| {
+                value * z + Complex::from(coefficient.to_f64())
+            })
+    }
+
+    /// Exact value at a rational `x`.
+    pub fn evaluate_rational(&self, x: &Rational) -> Rational {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Rational::zero(), |value, coefficient| {
+                value * x + coefficient
+            })
+    }
+
+    /// `Σ|a_i| * modulus^i`, the size of the terms summed by `evaluate` at a point of
+    /// this modulus.
+    pub fn magnitude(&self, modulus: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |value, coefficient| {
+                value * modulus + coefficient.to_f64().abs()
+            })
+    }
+
+    /// Cauchy's bound `1 + max |a_i / a_n|`, above the modulus of every root, zero for a
+    /// constant.
+    pub fn root_bound(&self) -> Rational {
+        if self.degree == 0 {
+            return Rational::zero();
+        }
+        let leading: &Rational = self.leading_coefficient();
+        self.coefficients[..self.degree]
+            .iter()
+            .map(|coefficient| (coefficient / leading).abs())
+            .max()
+            .unwrap_or_else(Rational::zero)
+            + Rational::one()
+    }
+
+    /// Number of distinct real roots in `(a, b]`, from the Sturm sequence.
+    pub fn count_real_roots(&self, a: &Rational, b: &Rational) -> usize {
+        sturm::count_real_roots(self, a, b)
+    }
+
+    /// One interval `(a, b]` around each distinct real root, in increasing order.
+    pub fn isolate_real_roots(&self) -> Vec<(Rational, Rational)> {
+        sturm::isolate_real_roots(self)
+    }
+
+    /// Solve with the built-in solvers.
     pub fn solve(&mut self) {
-        self.solutions = match self.degree {
-            0 => None,
-            1 => solve_linear(&self.coefficients),
-            2 => solve_quadratic(&self.coefficients),
-            _ => {
-                println!("Degree > 2 not supported");
-                None
-            }
+        self.solve_with(&SolverRegistry::new());
+    }
+
+    /// Solve with the numerical root finder whatever the degree.
+    pub fn solve_numerically(&mut self) {
+        self.solve_with(&SolverRegistry::numerical());
+    }
+
+    pub fn solve_with(&mut self, registry: &SolverRegistry) {
+        let derivation: Derivation = match registry.find(&self.coefficients) {
+            Some(solver) => solver.solve(&self.coefficients),
+            None => Derivation::new(
+                Vec::new(),
+                SolutionSet::Unsupported {
+                    reason: format!("{}{}", NO_SOLVER, self.degree),
+                },
+            ),
         };
+        self.record(derivation);
+    }
+
+    /// Private part
+    fn record(&mut self, derivation: Derivation) {
+        self.steps = derivation.steps;
+        self.solutions = Some(derivation.solutions);
+    }
+}
+
+impl Neg for &Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        self.scale(&Rational::from(-1))
     }
 }
+
+impl Neg for Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        -&self
+    }
+}
+
+impl Mul<&Rational> for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: &Rational) -> Polynomial {
+        self.scale(rhs)
+    }
+}
+
+impl Mul<Rational> for Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: Rational) -> Polynomial {
+        self.scale(&rhs)
+    }
+}
+
+fn add(lhs: &Polynomial, rhs: &Polynomial) -> Polynomial {
+    let length: usize = lhs.coefficients.len().max(rhs.coefficients.len());
+    let zero: Rational = Rational::zero();
+    let coefficients: Vec<Rational> = (0..length)
+        .map(|i| {
+            lhs.coefficients.get(i).unwrap_or(&zero) + rhs.coefficients.get(i).unwrap_or(&zero)
+        })
+        .collect();
+    Polynomial::from_coefficients(&coefficients)
+}
+
+fn sub(lhs: &Polynomial, rhs: &Polynomial) -> Polynomial {
+    add(lhs, &-rhs)
+}
+
+fn mul(lhs: &Polynomial, rhs: &Polynomial) -> Polynomial {
+    let mut coefficients: Vec<Rational> =
+        vec![Rational::zero(); lhs.coefficients.len() + rhs.coefficients.len() - 1];
+    for (i, a) in lhs.coefficients.iter().enumerate() {
+        for (j, b) in rhs.coefficients.iter().enumerate() {
+            coefficients[i + j] += &(a * b);
+        }
+    }
+    Polynomial::from_coefficients(&coefficients)
+}
+
+forward_binop!(Polynomial, Add, add, add);
+forward_binop!(Polynomial, Sub, sub, sub);
+forward_binop!(Polynomial, Mul, mul, mul);
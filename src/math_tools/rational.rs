@@ -0,0 +1,304 @@
+use crate::math_tools::big_int::BigInt;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Exact fraction `numerator / denominator`, always kept irreducible with a positive
+/// denominator so that equal values have equal representations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: BigInt,
+    denominator: BigInt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRationalError;
+
+impl Rational {
+    pub fn new(numerator: BigInt, denominator: BigInt) -> Self {
+        if denominator.is_zero() {
+            panic!("Error: Rational with a zero denominator.");
+        }
+
+        let divisor: BigInt = numerator.gcd(&denominator);
+        let (mut numerator, mut denominator) = (&numerator / &divisor, &denominator / &divisor);
+        if denominator.is_negative() {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+        Rational {
+            numerator,
+            denominator,
+        }
+    }
+
+    pub fn zero() -> Self {
+        Rational::from(0)
+    }
+
+    pub fn one() -> Self {
+        Rational::from(1)
+    }
+
+    pub fn numerator(&self) -> &BigInt {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &BigInt {
+        &self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.numerator.is_negative()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator.is_one()
+    }
+
+    pub fn abs(&self) -> Self {
+        Rational {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    pub fn recip(&self) -> Self {
+        Rational::new(self.denominator.clone(), self.numerator.clone())
+    }
+
+    pub fn pow(&self, exponent: u32) -> Self {
+        Rational {
+            numerator: self.numerator.pow(exponent),
+            denominator: self.denominator.pow(exponent),
+        }
+    }
+
+    /// Exact square root, when both terms of the fraction are perfect squares.
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.is_negative() {
+            return None;
+        }
+        let numerator: BigInt = self.numerator.sqrt();
+        let denominator: BigInt = self.denominator.sqrt();
+
+        if &numerator * &numerator == self.numerator
+            && &denominator * &denominator == self.denominator
+        {
+            Some(Rational {
+                numerator,
+                denominator,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Nearest `f64`, computed from a 64-bit quotient so that huge terms do not overflow.
+    pub fn to_f64(&self) -> f64 {
+        if self.is_zero() {
+            return 0.0;
+        }
+
+        let shift: i64 = 64 + self.denominator.bits() as i64 - self.numerator.bits() as i64;
+        let quotient: BigInt = if shift >= 0 {
+            &self.numerator.shl(shift as u64) / &self.denominator
+        } else {
+            &self.numerator / &self.denominator.shl(shift.unsigned_abs())
+        };
+
+        let mut value: f64 = quotient.to_f64();
+        let mut exponent: i64 = -shift;
+        while exponent != 0 {
+            let step: i64 = exponent.clamp(-1000, 1000);
+            value *= 2f64.powi(step as i32);
+            exponent -= step;
+        }
+        value
+    }
+
+    /// Integer or `numerator/denominator` writing, as in `-7/2`, whatever the denominator.
+    pub fn to_fraction(&self) -> String {
+        match self.is_integer() {
+            true => self.numerator.to_string(),
+            false => format!("{}/{}", self.numerator, self.denominator),
+        }
+    }
+
+    /// Exact decimal writing of the value, when the denominator only has 2 and 5 as
+    /// prime factors.
+    pub fn to_decimal(&self) -> Option<String> {
+        if self.is_integer() {
+            return Some(self.numerator.to_string());
+        }
+
+        let two: BigInt = BigInt::from(2);
+        let five: BigInt = BigInt::from(5);
+        let mut remaining: BigInt = self.denominator.clone();
+        let (mut twos, mut fives): (u32, u32) = (0, 0);
+        while (&remaining % &two).is_zero() {
+            remaining = &remaining / &two;
+            twos += 1;
+        }
+        while (&remaining % &five).is_zero() {
+            remaining = &remaining / &five;
+            fives += 1;
+        }
+        if !remaining.is_one() {
+            return None;
+        }
+
+        let digits: u32 = twos.max(fives);
+        let scaled: BigInt =
+            &(&self.numerator.abs() * &BigInt::from(10).pow(digits)) / &self.denominator;
+        let scaled: String = format!("{:0>width$}", scaled, width = digits as usize + 1);
+        let (integer, fraction) = scaled.split_at(scaled.len() - digits as usize);
+
+        let sign: &str = if self.is_negative() { "-" } else { "" };
+        Some(format!("{}{}.{}", sign, integer, fraction))
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(value: BigInt) -> Self {
+        Rational {
+            numerator: value,
+            denominator: BigInt::one(),
+        }
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational::from(BigInt::from(value))
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(value: i32) -> Self {
+        Rational::from(BigInt::from(value))
+    }
+}
+
+impl FromStr for Rational {
+    type Err = ParseRationalError;
+
+    /// Decimal literal such as `3`, `-0.25`, `.5` or `4.`, read exactly.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (integer, fraction): (&str, &str) = text.split_once('.').unwrap_or((text, ""));
+        let (sign, integer): (&str, &str) = match integer.as_bytes().first() {
+            Some(b'-') => ("-", &integer[1..]),
+            Some(b'+') => ("", &integer[1..]),
+            _ => ("", integer),
+        };
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(ParseRationalError);
+        }
+        if !(integer.bytes().chain(fraction.bytes())).all(|c| c.is_ascii_digit()) {
+            return Err(ParseRationalError);
+        }
+
+        let numerator: BigInt = format!("{}0{}{}", sign, integer, fraction)
+            .parse::<BigInt>()
+            .map_err(|_| ParseRationalError)?;
+        let denominator: BigInt = BigInt::from(10).pow(fraction.len() as u32);
+        Ok(Rational::new(numerator, denominator))
+    }
+}
+
+impl fmt::Display for Rational {
+    /// Terminating values are written as decimals (`0.25`), the others as fractions (`1/3`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_decimal() {
+            Some(decimal) => f.pad(&decimal),
+            None => f.pad(&format!("{}/{}", self.numerator, self.denominator)),
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -&self.numerator,
+            denominator: self.denominator.clone(),
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+fn add(lhs: &Rational, rhs: &Rational) -> Rational {
+    Rational::new(
+        &lhs.numerator * &rhs.denominator + &rhs.numerator * &lhs.denominator,
+        &lhs.denominator * &rhs.denominator,
+    )
+}
+
+fn sub(lhs: &Rational, rhs: &Rational) -> Rational {
+    Rational::new(
+        &lhs.numerator * &rhs.denominator - &rhs.numerator * &lhs.denominator,
+        &lhs.denominator * &rhs.denominator,
+    )
+}
+
+fn mul(lhs: &Rational, rhs: &Rational) -> Rational {
+    Rational::new(
+        &lhs.numerator * &rhs.numerator,
+        &lhs.denominator * &rhs.denominator,
+    )
+}
+
+fn div(lhs: &Rational, rhs: &Rational) -> Rational {
+    if rhs.is_zero() {
+        panic!("Error: Rational division by zero.");
+    }
+    Rational::new(
+        &lhs.numerator * &rhs.denominator,
+        &lhs.denominator * &rhs.numerator,
+    )
+}
+
+forward_binop!(Rational, Add, add, add);
+forward_binop!(Rational, Sub, sub, sub);
+forward_binop!(Rational, Mul, mul, mul);
+forward_binop!(Rational, Div, div, div);
+
+impl AddAssign<&Rational> for Rational {
+    fn add_assign(&mut self, rhs: &Rational) {
+        *self = add(self, rhs);
+    }
+}
+
+impl SubAssign<&Rational> for Rational {
+    fn sub_assign(&mut self, rhs: &Rational) {
+        *self = sub(self, rhs);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::math_tools::complex::Complex;
+use crate::math_tools::rational::Rational;
+use crate::math_tools::surd::Surd;
+use std::fmt;
+
+/// A value found by a solver: exact when it is rational or a quadratic surd,
+/// approximated otherwise, and complex when it is not real.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Root {
+    Exact(Rational),
+    Surd(Surd),
+    Approximate(f64),
+    Complex(Complex),
+}
+
+impl Root {
+    pub fn is_real(&self) -> bool {
+        !matches!(self, Root::Complex(_))
+    }
+
+    pub fn to_complex(&self) -> Complex {
+        match self {
+            Root::Complex(value) => *value,
+            _ => Complex::from(self.to_f64()),
+        }
+    }
+
+    /// Value of a real root, or real part of a complex one.
+    pub fn to_f64(&self) -> f64 {
+        match self {
+            Root::Exact(value) => value.to_f64(),
+            Root::Surd(value) => value.to_f64(),
+            Root::Approximate(value) => *value,
+            Root::Complex(value) => value.real,
+        }
+    }
+}
+
+impl fmt::Display for Root {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Root::Complex(value) => write!(f, "{}", value),
+            _ => write!(f, "{}", self.to_f64()),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::math_tools::complex::Complex;
+use crate::math_tools::root::Root;
+
+/// A distinct root of a polynomial, with the number of times it is repeated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    pub root: Root,
+    pub multiplicity: usize,
+}
+
+/// Every outcome of solving a polynomial equation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolutionSet {
+    /// No value satisfies the equation, as in `5 = 0`.
+    Contradiction,
+    /// Every value satisfies the equation, as in `0 = 0`.
+    Identity,
+    Finite(Vec<Solution>),
+    Unsupported {
+        reason: String,
+    },
+}
+
+impl Solution {
+    pub fn new(root: Root, multiplicity: usize) -> Self {
+        Solution { root, multiplicity }
+    }
+
+    pub fn value(&self) -> Complex {
+        self.root.to_complex()
+    }
+}
+
+/// Group the roots found by a solver, each counted as many times as it is repeated: the
+/// real ones in increasing order, then the complex ones by real part, each one before
+/// its conjugate.
+pub fn collect_solutions(mut roots: Vec<Root>) -> Vec<Solution> {
+    let key = |root: &Root| -> (bool, f64, f64) {
+        let value: Complex = root.to_complex();
+        (!root.is_real(), value.real, -value.imaginary)
+    };
+    roots.sort_by(|x1, x2| {
+        let (x1, x2) = (key(x1), key(x2));
+        x1.0.cmp(&x2.0)
+            .then(x1.1.total_cmp(&x2.1))
+            .then(x1.2.total_cmp(&x2.2))
+    });
+
+    let mut solutions: Vec<Solution> = Vec::new();
+    for root in roots {
+        match solutions.last_mut() {
+            Some(last) if last.root == root => last.multiplicity += 1,
+            _ => solutions.push(Solution::new(root, 1)),
+        }
+    }
+    solutions
+}
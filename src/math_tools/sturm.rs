@@ -0,0 +1,79 @@
+use crate::math_tools::polynomial::Polynomial;
+use crate::math_tools::rational::Rational;
+
+/// Sturm sequence `p0 = p / gcd(p, p')`, `p1 = p0'`, `p(i+1) = -(p(i-1) mod p(i))`, built
+/// from the square-free part of `p` so that each distinct root is simple. For `a < b`,
+/// the number of distinct roots in `(a, b]` is then `V(a) - V(b)`, `V(x)` being the
+/// number of sign changes along the sequence evaluated at `x`.
+pub fn sturm_sequence(polynomial: &Polynomial) -> Vec<Polynomial> {
+    if polynomial.degree == 0 {
+        return vec![polynomial.clone()];
+    }
+    let square_free: Polynomial = polynomial
+        .div_rem(&polynomial.gcd(&polynomial.derivative()))
+        .0;
+    let derivative: Polynomial = square_free.derivative();
+    let mut sequence: Vec<Polynomial> = vec![square_free, derivative];
+
+    while sequence[sequence.len() - 1].degree > 0 {
+        let remainder: Polynomial = sequence[sequence.len() - 2]
+            .div_rem(&sequence[sequence.len() - 1])
+            .1;
+        if remainder.is_zero() {
+            break;
+        }
+        sequence.push(-remainder);
+    }
+    sequence
+}
+
+/// Sign changes along the sequence evaluated at `x`, zeros being skipped.
+pub fn sign_variations(sequence: &[Polynomial], x: &Rational) -> usize {
+    let signs: Vec<bool> = sequence
+        .iter()
+        .map(|polynomial| polynomial.evaluate_rational(x))
+        .filter(|value| !value.is_zero())
+        .map(|value| value.is_negative())
+        .collect();
+    signs.windows(2).filter(|pair| pair[0] != pair[1]).count()
+}
+
+/// Number of distinct real roots in `(a, b]`, zero when `b <= a`.
+pub fn count_real_roots(polynomial: &Polynomial, a: &Rational, b: &Rational) -> usize {
+    if b <= a || polynomial.is_zero() {
+        return 0;
+    }
+    let sequence: Vec<Polynomial> = sturm_sequence(polynomial);
+    sign_variations(&sequence, a) - sign_variations(&sequence, b)
+}
+
+/// Disjoint intervals `(a, b]` in increasing order, each containing exactly one of the
+/// distinct real roots, found by bisection of `(-bound, bound]`.
+pub fn isolate_real_roots(polynomial: &Polynomial) -> Vec<(Rational, Rational)> {
+    if polynomial.degree == 0 {
+        return Vec::new();
+    }
+    let sequence: Vec<Polynomial> = sturm_sequence(polynomial);
+    let bound: Rational = polynomial.root_bound();
+    let mut intervals: Vec<(Rational, Rational)> = Vec::new();
+    bisect(&sequence, (-&bound, bound), &mut intervals);
+    intervals
+}
+
+/// Private part
+fn bisect(
+    sequence: &[Polynomial],
+    (low, high): (Rational, Rational),
+    intervals: &mut Vec<(Rational, Rational)>,
+) {
+    let count: usize = sign_variations(sequence, &low) - sign_variations(sequence, &high);
+    match count {
+        0 => {}
+        1 => intervals.push((low, high)),
+        _ => {
+            let middle: Rational = (&low + &high) / Rational::from(2);
+            bisect(sequence, (low, middle.clone()), intervals);
+            bisect(sequence, (middle, high), intervals);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::constants::math_tools_constants::SQUARE_FACTOR_LIMIT;
+use crate::math_tools::basic;
+use crate::math_tools::big_int::BigInt;
+use crate::math_tools::rational::Rational;
+use std::fmt;
+
+/// Exact irrational value `(constant + coefficient * √radicand) / denominator`, with a
+/// square-free radicand greater than 1, a positive denominator and no factor common
+/// to the three integers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Surd {
+    pub constant: BigInt,
+    pub coefficient: BigInt,
+    pub radicand: BigInt,
+    pub denominator: BigInt,
+}
+
+impl Surd {
+    /// `rational + factor * √value`, or `None` when `√value` is rational.
+    pub fn new(rational: &Rational, factor: &Rational, value: &Rational) -> Option<Self> {
+        if value.is_negative() || factor.is_zero() {
+            return None;
+        }
+
+        // √(n/m) = √(n * m) / m = s√d / m
+        let (square_root, radicand) =
+            square_free_decomposition(&(value.numerator() * value.denominator()));
+        if radicand.is_one() {
+            return None;
+        }
+        let factor: Rational = factor * Rational::new(square_root, value.denominator().clone());
+
+        let denominator: BigInt = rational.denominator().lcm(factor.denominator());
+        let constant: BigInt = rational.numerator() * &(&denominator / rational.denominator());
+        let coefficient: BigInt = factor.numerator() * &(&denominator / factor.denominator());
+
+        let divisor: BigInt = constant.gcd(&coefficient).gcd(&denominator);
+        Some(Surd {
+            constant: &constant / &divisor,
+            coefficient: &coefficient / &divisor,
+            radicand,
+            denominator: &denominator / &divisor,
+        })
+    }
+
+    /// Same value with the sign of the square root flipped.
+    pub fn conjugate(&self) -> Self {
+        Surd {
+            coefficient: -&self.coefficient,
+            ..self.clone()
+        }
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let constant: f64 = self.constant.to_f64();
+        let root: f64 = self.coefficient.to_f64() * basic::square_root(self.radicand.to_f64());
+        let denominator: f64 = self.denominator.to_f64();
+
+        if self.constant.is_negative() == self.coefficient.is_negative() {
+            (constant + root) / denominator
+        } else {
+            // p + q√d = (p² - q²d) / (p - q√d) avoids cancelling two close values.
+            let norm: BigInt = &self.constant * &self.constant
+                - &(&self.coefficient * &self.coefficient) * &self.radicand;
+            norm.to_f64() / (denominator * (constant - root))
+        }
+    }
+
+    /// Both conjugates in one expression, as in `(-3 ± √17) / 4`.
+    pub fn format_conjugates(&self) -> String {
+        self.format_with_sign("±", "±")
+    }
+
+    /// Private part
+    fn format_with_sign(&self, positive: &str, negative: &str) -> String {
+        let sign: &str = if self.coefficient.is_negative() {
+            negative
+        } else {
+            positive
+        };
+        let coefficient: BigInt = self.coefficient.abs();
+        let root: String = if coefficient.is_one() {
+            format!("√{}", self.radicand)
+        } else {
+            format!("{}√{}", coefficient, self.radicand)
+        };
+
+        let numerator: String = if self.constant.is_zero() {
+            format!("{}{}", sign.trim_start_matches('+'), root)
+        } else {
+            format!("{} {} {}", self.constant, sign, root)
+        };
+
+        match (self.denominator.is_one(), self.constant.is_zero()) {
+            (true, _) => numerator,
+            (false, true) => format!("{} / {}", numerator, self.denominator),
+            (false, false) => format!("({}) / {}", numerator, self.denominator),
+        }
+    }
+}
+
+impl fmt::Display for Surd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.format_with_sign("+", "-"))
+    }
+}
+
+/// Split `value` into `(s, d)` with `value = s² * d` and `d` free of the square factors
+/// found below `SQUARE_FACTOR_LIMIT`, or of any square factor when the remaining
+/// cofactor is itself a perfect square.
+pub fn square_free_decomposition(value: &BigInt) -> (BigInt, BigInt) {
+    let mut remaining: BigInt = value.abs();
+    let mut square_root: BigInt = BigInt::one();
+    let mut factor: u64 = 2;
+
+    while factor <= SQUARE_FACTOR_LIMIT && BigInt::from(factor * factor) <= remaining {
+        let square: BigInt = BigInt::from(factor * factor);
+        while (&remaining % &square).is_zero() {
+            remaining = &remaining / &square;
+            square_root = &square_root * &BigInt::from(factor);
+        }
+        factor += 1;
+    }
+
+    let cofactor_root: BigInt = remaining.sqrt();
+    if &cofactor_root * &cofactor_root == remaining {
+        return (&square_root * &cofactor_root, BigInt::one());
+    }
+    (square_root, remaining)
+}
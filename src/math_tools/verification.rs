@@ -0,0 +1,40 @@
+use crate::constants::math_tools_constants::VERIFICATION_TOLERANCE;
+use crate::math_tools::polynomial::Polynomial;
+use crate::math_tools::root::Root;
+use crate::math_tools::solution::SolutionSet;
+
+/// Solution substituted back into the reduced form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Verification {
+    pub root: Root,
+    /// `|P(x)|`, zero for an exact rational root.
+    pub residual: f64,
+    /// Whether the residual stays within the tolerance, relative to the size of the terms
+    /// `Σ|a_i| * |x|^i` that cancel out.
+    pub accepted: bool,
+}
+
+/// Substitute each solution found by the solver, none when the solution set is not finite.
+pub fn verify_solutions(polynomial: &Polynomial) -> Vec<Verification> {
+    let solutions = match &polynomial.solutions {
+        Some(SolutionSet::Finite(solutions)) => solutions,
+        _ => return Vec::new(),
+    };
+    solutions
+        .iter()
+        .map(|solution| verify_root(polynomial, &solution.root))
+        .collect()
+}
+
+pub fn verify_root(polynomial: &Polynomial, root: &Root) -> Verification {
+    let residual: f64 = match root {
+        Root::Exact(value) => polynomial.evaluate_rational(value).abs().to_f64(),
+        _ => polynomial.evaluate_complex(root.to_complex()).modulus(),
+    };
+    let scale: f64 = polynomial.magnitude(root.to_complex().modulus()).max(1.0);
+    Verification {
+        root: root.clone(),
+        residual,
+        accepted: residual <= VERIFICATION_TOLERANCE * scale,
+    }
+}
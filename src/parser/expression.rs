@@ -0,0 +1,370 @@
+use crate::constants::parsing_constants::MAX_DEGREE;
+use crate::errors::{ComputorError, Span};
+use crate::math_tools::rational::Rational;
+use crate::parser::input_parser::ParseMode;
+use crate::parser::lexer::{Token, TokenKind};
+use crate::parser::tools::{add_coefficients, multiply_coefficients, power_coefficients};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpressionKind {
+    Number(Rational),
+    Variable,
+    Negate(Box<Expression>),
+    Add(Box<Expression>, Box<Expression>),
+    Subtract(Box<Expression>, Box<Expression>),
+    Multiply(Box<Expression>, Box<Expression>),
+    Divide(Box<Expression>, Box<Expression>),
+    Power(Box<Expression>, usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    pub kind: ExpressionKind,
+    pub span: Span,
+}
+
+impl Expression {
+    fn new(kind: ExpressionKind, span: Span) -> Self {
+        Expression { kind, span }
+    }
+
+    fn binary(
+        kind: fn(Box<Expression>, Box<Expression>) -> ExpressionKind,
+        lhs: Self,
+        rhs: Self,
+    ) -> Self {
+        let span: Span = Span::new(lhs.span.start, rhs.span.end);
+        Expression::new(kind(Box::new(lhs), Box::new(rhs)), span)
+    }
+
+    /// Coefficients of the expanded expression, indexed by power.
+    pub fn expand(&self) -> Vec<Rational> {
+        match &self.kind {
+            ExpressionKind::Number(value) => vec![value.clone()],
+            ExpressionKind::Variable => vec![Rational::zero(), Rational::one()],
+            ExpressionKind::Negate(operand) => operand.expand().into_iter().map(|c| -c).collect(),
+            ExpressionKind::Add(lhs, rhs) => add_coefficients(lhs.expand(), &rhs.expand()),
+            ExpressionKind::Subtract(lhs, rhs) => {
+                let negated: Vec<Rational> = rhs.expand().into_iter().map(|c| -c).collect();
+                add_coefficients(lhs.expand(), &negated)
+            }
+            ExpressionKind::Multiply(lhs, rhs) => {
+                multiply_coefficients(&lhs.expand(), &rhs.expand())
+            }
+            ExpressionKind::Divide(lhs, rhs) => {
+                let divisor: Rational = rhs.expand()[0].clone();
+                lhs.expand().into_iter().map(|c| c / &divisor).collect()
+            }
+            ExpressionKind::Power(base, exponent) => power_coefficients(&base.expand(), *exponent),
+        }
+    }
+
+    /// Upper bound of the degree of the expanded expression, found without expanding it.
+    pub fn degree(&self) -> usize {
+        match &self.kind {
+            ExpressionKind::Number(_) => 0,
+            ExpressionKind::Variable => 1,
+            ExpressionKind::Negate(operand) => operand.degree(),
+            ExpressionKind::Add(lhs, rhs) | ExpressionKind::Subtract(lhs, rhs) => {
+                lhs.degree().max(rhs.degree())
+            }
+            ExpressionKind::Multiply(lhs, rhs) => lhs.degree().saturating_add(rhs.degree()),
+            ExpressionKind::Divide(lhs, _) => lhs.degree(),
+            ExpressionKind::Power(base, exponent) => base.degree().saturating_mul(*exponent),
+        }
+    }
+}
+
+/// Recursive descent parser over the tokens of one equation:
+///
+/// ```text
+/// equation := sum ('=' sum?)?
+/// sum      := product (('+' | '-') product)*
+/// product  := unary (('*' | '/')? unary)*
+/// unary    := ('+' | '-') unary | power
+/// power    := primary ('^' integer | superscript)?
+/// primary  := number | X | '(' sum ')'
+/// ```
+///
+/// A product without `*` is only accepted before a parenthesis in strict mode, and
+/// before any number, variable or parenthesis in natural mode. Divisors must be
+/// non-zero constants so that the expansion stays a polynomial.
+pub struct ExpressionParser<'a> {
+    input: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+    mode: ParseMode,
+}
+
+impl<'a> ExpressionParser<'a> {
+    pub fn new(input: &'a str, tokens: Vec<Token>, mode: ParseMode) -> Self {
+        ExpressionParser {
+            input,
+            tokens,
+            position: 0,
+            mode,
+        }
+    }
+
+    /// Both members of the equation, the right one being `None` when it is omitted.
+    pub fn parse_equation(&mut self) -> Result<(Expression, Option<Expression>), ComputorError> {
+        if matches!(self.peek_kind(), None | Some(TokenKind::Equal)) {
+            return Err(ComputorError::EmptyInput);
+        }
+
+        let left: Expression = self.parse_sum()?;
+        let right: Option<Expression> =
+            if self.eat(&TokenKind::Equal).is_some() && self.peek().is_some() {
+                Some(self.parse_sum()?)
+            } else {
+                None
+            };
+
+        match self.peek() {
+            Some(token) => Err(self.unexpected_token(token)),
+            None => Ok((left, right)),
+        }
+    }
+
+    /// Private part
+    fn parse_sum(&mut self) -> Result<Expression, ComputorError> {
+        let mut lhs: Expression = self.parse_product()?;
+
+        loop {
+            let kind: fn(Box<Expression>, Box<Expression>) -> ExpressionKind =
+                match self.peek_kind() {
+                    Some(TokenKind::Plus) => ExpressionKind::Add,
+                    Some(TokenKind::Minus) => ExpressionKind::Subtract,
+                    _ => return Ok(lhs),
+                };
+            self.position += 1;
+            let rhs: Expression = self.parse_product()?;
+            lhs = Expression::binary(kind, lhs, rhs);
+        }
+    }
+
+    fn parse_product(&mut self) -> Result<Expression, ComputorError> {
+        let mut lhs: Expression = self.parse_unary()?;
+
+        loop {
+            let rhs: Expression = match self.peek_kind() {
+                Some(TokenKind::Star) => {
+                    self.position += 1;
+                    self.parse_unary()?
+                }
+                Some(TokenKind::Slash) => {
+                    self.position += 1;
+                    let divisor: Expression = self.parse_unary()?;
+                    self.check_divisor(&divisor)?;
+                    lhs = Expression::binary(ExpressionKind::Divide, lhs, divisor);
+                    continue;
+                }
+                Some(TokenKind::LeftParenthesis) => self.parse_unary()?,
+                Some(TokenKind::Number(_) | TokenKind::Variable) => {
+                    let rhs: Expression = self.parse_unary()?;
+                    if self.mode == ParseMode::Strict {
+                        return Err(self.implicit_product_error(&lhs, &rhs));
+                    }
+                    rhs
+                }
+                _ => return Ok(lhs),
+            };
+            lhs = Expression::binary(ExpressionKind::Multiply, lhs, rhs);
+            self.check_degree(&lhs)?;
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expression, ComputorError> {
+        if let Some(sign) = self.eat(&TokenKind::Minus) {
+            let operand: Expression = self.parse_unary()?;
+            let span: Span = Span::new(sign.span.start, operand.span.end);
+            return Ok(Expression::new(
+                ExpressionKind::Negate(Box::new(operand)),
+                span,
+            ));
+        }
+        if let Some(sign) = self.eat(&TokenKind::Plus) {
+            let mut operand: Expression = self.parse_unary()?;
+            operand.span.start = sign.span.start;
+            return Ok(operand);
+        }
+        self.parse_power()
+    }
+
+    fn parse_power(&mut self) -> Result<Expression, ComputorError> {
+        let base: Expression = self.parse_primary()?;
+
+        let (exponent, end): (usize, usize) = match self.peek_kind() {
+            Some(TokenKind::Superscript(exponent)) => {
+                let exponent: usize = *exponent;
+                (exponent, self.advance().span.end)
+            }
+            Some(TokenKind::Caret) => {
+                let caret: Token = self.advance();
+                let exponent: Option<Token> = self.eat_number();
+                let end: usize = exponent
+                    .as_ref()
+                    .map_or(caret.span.end, |token| token.span.end);
+                let exponent: Option<usize> = exponent.and_then(|token| match token.kind {
+                    TokenKind::Number(text) => text.parse::<usize>().ok(),
+                    _ => None,
+                });
+
+                match exponent {
+                    Some(exponent) => (exponent, end),
+                    None => {
+                        let span: Span = Span::new(base.span.start, end);
+                        return Err(ComputorError::InvalidPower {
+                            text: self.text(span),
+                            span,
+                        });
+                    }
+                }
+            }
+            _ => return Ok(base),
+        };
+
+        let span: Span = Span::new(base.span.start, end);
+        let power: Expression =
+            Expression::new(ExpressionKind::Power(Box::new(base), exponent), span);
+        if exponent > MAX_DEGREE {
+            return Err(ComputorError::DegreeTooHigh {
+                text: self.text(span),
+                span,
+            });
+        }
+        self.check_degree(&power)?;
+        Ok(power)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expression, ComputorError> {
+        let Some(token) = self.peek().cloned() else {
+            let end: usize = self.input.len();
+            return Err(ComputorError::UnexpectedEnd {
+                span: Span::new(end, end),
+            });
+        };
+
+        match &token.kind {
+            TokenKind::Number(text) => {
+                self.position += 1;
+                let value: Rational =
+                    text.parse::<Rational>()
+                        .map_err(|_| ComputorError::InvalidCoefficient {
+                            text: text.to_string(),
+                            span: token.span,
+                        })?;
+                Ok(Expression::new(ExpressionKind::Number(value), token.span))
+            }
+            TokenKind::Variable => {
+                self.position += 1;
+                Ok(Expression::new(ExpressionKind::Variable, token.span))
+            }
+            TokenKind::LeftParenthesis => {
+                self.position += 1;
+                let mut inner: Expression = self.parse_sum()?;
+                let closing: Token = self
+                    .eat(&TokenKind::RightParenthesis)
+                    .ok_or(ComputorError::UnclosedParenthesis { span: token.span })?;
+                inner.span = Span::new(token.span.start, closing.span.end);
+                Ok(inner)
+            }
+            _ => Err(self.unexpected_token(&token)),
+        }
+    }
+
+    /// Strict mode rejects `3X` and `X2`, reported as a malformed coefficient or power
+    /// so that the hint can suggest the strict spelling.
+    fn implicit_product_error(&self, lhs: &Expression, rhs: &Expression) -> ComputorError {
+        let unsigned_lhs: &Expression = match &lhs.kind {
+            ExpressionKind::Negate(operand) => operand,
+            _ => lhs,
+        };
+        let span: Span = Span::new(unsigned_lhs.span.start, rhs.span.end);
+
+        match (&unsigned_lhs.kind, &rhs.kind) {
+            (ExpressionKind::Number(_), _) => ComputorError::InvalidCoefficient {
+                text: self.text(span),
+                span,
+            },
+            (ExpressionKind::Variable, ExpressionKind::Number(_)) => ComputorError::InvalidPower {
+                text: self.text(span),
+                span,
+            },
+            _ => ComputorError::UnexpectedToken {
+                text: self.text(rhs.span),
+                span: rhs.span,
+            },
+        }
+    }
+
+    /// Expanding is quadratic in the degree, so products and powers are bounded before.
+    fn check_degree(&self, expression: &Expression) -> Result<(), ComputorError> {
+        if expression.degree() > MAX_DEGREE {
+            Err(ComputorError::DegreeTooHigh {
+                text: self.text(expression.span),
+                span: expression.span,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_divisor(&self, divisor: &Expression) -> Result<(), ComputorError> {
+        let coefficients: Vec<Rational> = divisor.expand();
+
+        if coefficients[1..].iter().any(|c| !c.is_zero()) {
+            Err(ComputorError::NonConstantDivisor {
+                text: self.text(divisor.span),
+                span: divisor.span,
+            })
+        } else if coefficients[0].is_zero() {
+            Err(ComputorError::DivisionByZero {
+                text: self.text(divisor.span),
+                span: divisor.span,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn unexpected_token(&self, token: &Token) -> ComputorError {
+        ComputorError::UnexpectedToken {
+            text: self.text(token.span),
+            span: token.span,
+        }
+    }
+
+    /// Source text of `span` with its spaces removed.
+    fn text(&self, span: Span) -> String {
+        self.input[span.start..span.end].replace(' ', "")
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn peek_kind(&self) -> Option<&TokenKind> {
+        self.peek().map(|token| &token.kind)
+    }
+
+    fn advance(&mut self) -> Token {
+        self.position += 1;
+        self.tokens[self.position - 1].clone()
+    }
+
+    fn eat(&mut self, kind: &TokenKind) -> Option<Token> {
+        if self.peek_kind() == Some(kind) {
+            Some(self.advance())
+        } else {
+            None
+        }
+    }
+
+    fn eat_number(&mut self) -> Option<Token> {
+        match self.peek_kind() {
+            Some(TokenKind::Number(_)) => Some(self.advance()),
+            _ => None,
+        }
+    }
+}
@@ -1,11 +1,31 @@
-use crate::parser::tools::{parse_equation, split_input, sum_coefficients};
-use std::collections::HashMap;
+use crate::errors::ComputorError;
+use crate::math_tools::rational::Rational;
+use crate::parser::expression::{Expression, ExpressionParser};
+use crate::parser::lexer::{tokenize, Token};
+use crate::parser::tools::sum_coefficients;
 
-pub fn parse_input(input: &str) -> Result<Vec<f64>, String> {
-    let equation_members: (String, String) = split_input(input)?;
+/// `Strict` only accepts explicit products such as `3 * X^2` or `2 * (X + 1)^2`, with
+/// `2(X + 1)` as the single shorthand. `Natural` also accepts implicit multiplication
+/// (`4x`, `x(x - 1)`), a lowercase variable and superscript powers (`x²`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    #[default]
+    Strict,
+    Natural,
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Rational>, ComputorError> {
+    parse_input_with_mode(input, ParseMode::Strict)
+}
+
+pub fn parse_input_with_mode(input: &str, mode: ParseMode) -> Result<Vec<Rational>, ComputorError> {
+    let tokens: Vec<Token> = tokenize(input, mode)?;
+    let (left, right): (Expression, Option<Expression>) =
+        ExpressionParser::new(input, tokens, mode).parse_equation()?;
 
-    let left_coefficients: HashMap<usize, f64> = parse_equation(&equation_members.0)?;
-    let right_coefficients: HashMap<usize, f64> = parse_equation(&equation_members.1)?;
+    let left_coefficients: Vec<Rational> = left.expand();
+    let right_coefficients: Vec<Rational> =
+        right.map_or_else(|| vec![Rational::zero()], |right| right.expand());
 
     Ok(sum_coefficients(left_coefficients, right_coefficients))
 }
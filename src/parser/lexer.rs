@@ -0,0 +1,95 @@
+use crate::constants::parsing_constants::{SUPERSCRIPT_DIGITS, VARIABLES};
+use crate::errors::{ComputorError, Span};
+use crate::parser::input_parser::ParseMode;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Number(String),
+    Variable,
+    Superscript(usize),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Caret,
+    LeftParenthesis,
+    RightParenthesis,
+    Equal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+/// Split `input` into tokens, spaces excluded. Lowercase variables and superscript powers
+/// are only recognised in natural mode.
+pub fn tokenize(input: &str, mode: ParseMode) -> Result<Vec<Token>, ComputorError> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut characters = input.char_indices().peekable();
+    let mut seen_equal: bool = false;
+
+    while let Some((start, c)) = characters.next() {
+        let mut end: usize = start + c.len_utf8();
+        let kind: TokenKind = match c {
+            ' ' => continue,
+            '+' => TokenKind::Plus,
+            '-' => TokenKind::Minus,
+            '*' => TokenKind::Star,
+            '/' => TokenKind::Slash,
+            '^' => TokenKind::Caret,
+            '(' => TokenKind::LeftParenthesis,
+            ')' => TokenKind::RightParenthesis,
+            '=' if !seen_equal => {
+                seen_equal = true;
+                TokenKind::Equal
+            }
+            'X' => TokenKind::Variable,
+            c if mode == ParseMode::Natural && VARIABLES.contains(c) => TokenKind::Variable,
+            c if c.is_ascii_digit() || c == '.' => {
+                while let Some(&(index, next)) = characters.peek() {
+                    if !(next.is_ascii_digit() || next == '.') {
+                        break;
+                    }
+                    end = index + 1;
+                    characters.next();
+                }
+                TokenKind::Number(input[start..end].to_string())
+            }
+            c if mode == ParseMode::Natural && superscript_digit(c).is_some() => {
+                let mut power: Option<usize> = superscript_digit(c);
+                while let Some(&(index, next)) = characters.peek() {
+                    let Some(digit) = superscript_digit(next) else {
+                        break;
+                    };
+                    power = power.and_then(|p| p.checked_mul(10)?.checked_add(digit));
+                    end = index + next.len_utf8();
+                    characters.next();
+                }
+                let power: usize = power.ok_or_else(|| ComputorError::InvalidPower {
+                    text: input[start..end].to_string(),
+                    span: Span::new(start, end),
+                })?;
+                TokenKind::Superscript(power)
+            }
+            character => {
+                return Err(ComputorError::UnexpectedCharacter {
+                    character,
+                    span: Span::new(start, end),
+                })
+            }
+        };
+        tokens.push(Token {
+            kind,
+            span: Span::new(start, end),
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Private part
+fn superscript_digit(c: char) -> Option<usize> {
+    SUPERSCRIPT_DIGITS.chars().position(|digit| digit == c)
+}
@@ -1,3 +1,5 @@
+pub mod expression;
 pub mod input_parser;
+pub mod lexer;
 mod tools;
-pub use crate::parser::input_parser::parse_input;
+pub use crate::parser::input_parser::{parse_input, parse_input_with_mode, ParseMode};
@@ -0,0 +1,64 @@
+use crate::constants::parsing_constants::{DERIVE_COMMAND, INTEGRATE_COMMAND, VARIABLES};
+use crate::constants::repl_constants::{
+    HELP_COMMAND, HISTORY_COMMAND, QUIT_COMMAND, STURM_COMMAND, UNKNOWN_COMMAND, VERIFY_COMMAND,
+};
+
+/// One line of the interactive mode. The targets are either the name of an equation
+/// bound earlier or an equation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    History,
+    Quit,
+    Bind { name: String, equation: String },
+    Solve(String),
+    Derive(String),
+    Integrate(String),
+    Verify(String),
+    Sturm(String),
+}
+
+pub fn parse_command(line: &str) -> Result<Command, String> {
+    let line: &str = line.trim();
+    if line.starts_with(':') {
+        return match line {
+            HELP_COMMAND => Ok(Command::Help),
+            HISTORY_COMMAND => Ok(Command::History),
+            QUIT_COMMAND => Ok(Command::Quit),
+            _ => Err(format!("{}{}", UNKNOWN_COMMAND, line)),
+        };
+    }
+
+    if let Some((keyword, target)) = line.split_once(char::is_whitespace) {
+        let target: String = target.trim().to_string();
+        match keyword {
+            DERIVE_COMMAND => return Ok(Command::Derive(target)),
+            INTEGRATE_COMMAND => return Ok(Command::Integrate(target)),
+            VERIFY_COMMAND => return Ok(Command::Verify(target)),
+            STURM_COMMAND => return Ok(Command::Sturm(target)),
+            _ => {}
+        }
+    }
+
+    match line.split_once('=') {
+        Some((name, equation)) if is_name(name.trim()) && !equation.trim().is_empty() => {
+            Ok(Command::Bind {
+                name: name.trim().to_string(),
+                equation: equation.trim().to_string(),
+            })
+        }
+        _ => Ok(Command::Solve(line.to_string())),
+    }
+}
+
+/// Names start with a letter other than the variable, followed by letters, digits or
+/// underscores, as in `p1`.
+pub fn is_name(text: &str) -> bool {
+    let mut characters = text.chars();
+    match characters.next() {
+        Some(first) if first.is_ascii_alphabetic() && !VARIABLES.contains(first) => {
+            characters.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,149 @@
+use crate::constants::repl_constants::{HELP, PROMPT, UNKNOWN_EQUATION, WELCOME};
+use crate::display::{display_error, display_polynomial};
+use crate::math_tools::polynomial::Polynomial;
+use crate::parser::ParseMode;
+use crate::repl::command::{is_name, parse_command, Command};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+/// State of the interactive mode: the lines entered so far and the named equations.
+pub struct Session {
+    mode: ParseMode,
+    history: Vec<String>,
+    equations: HashMap<String, Polynomial>,
+}
+
+impl Session {
+    pub fn new(mode: ParseMode) -> Self {
+        Session {
+            mode,
+            history: Vec::new(),
+            equations: HashMap::new(),
+        }
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    pub fn equation(&self, name: &str) -> Option<&Polynomial> {
+        self.equations.get(name)
+    }
+
+    /// Run one line and print its result, returning false once the user quits.
+    pub fn execute(&mut self, line: &str) -> bool {
+        let line: &str = line.trim();
+        if line.is_empty() {
+            return true;
+        }
+        self.history.push(line.to_string());
+
+        let command: Command = match parse_command(line) {
+            Ok(command) => command,
+            Err(message) => {
+                println!("Error: {}.", message);
+                return true;
+            }
+        };
+        match command {
+            Command::Quit => return false,
+            Command::Help => println!("{}", HELP),
+            Command::History => self.display_history(),
+            Command::Bind { name, equation } => {
+                if let Some(polynomial) = self.parse(&equation) {
+                    let polynomial: Polynomial = solved(polynomial);
+                    display_polynomial::display_solved_polynomial(&polynomial);
+                    self.equations.insert(name, polynomial);
+                }
+            }
+            Command::Solve(target) => {
+                if let Some(polynomial) = self.resolve(&target) {
+                    display_polynomial::display_solved_polynomial(&solved(polynomial));
+                }
+            }
+            Command::Derive(target) => {
+                if let Some(polynomial) = self.resolve(&target) {
+                    display_polynomial::display_derivative(&polynomial);
+                }
+            }
+            Command::Integrate(target) => {
+                if let Some(polynomial) = self.resolve(&target) {
+                    display_polynomial::display_antiderivative(&polynomial);
+                }
+            }
+            Command::Verify(target) => {
+                if let Some(polynomial) = self.resolve(&target) {
+                    display_polynomial::display_verification(&solved(polynomial));
+                }
+            }
+            Command::Sturm(target) => {
+                if let Some(polynomial) = self.resolve(&target) {
+                    display_polynomial::display_real_root_intervals(&polynomial);
+                }
+            }
+        }
+        true
+    }
+
+    // Private part
+    /// The equation bound to `target`, or `target` parsed as an equation.
+    fn resolve(&self, target: &str) -> Option<Polynomial> {
+        if let Some(polynomial) = self.equations.get(target) {
+            return Some(polynomial.clone());
+        }
+        if is_name(target) {
+            println!("Error: {}{}.", UNKNOWN_EQUATION, target);
+            return None;
+        }
+        self.parse(target)
+    }
+
+    fn parse(&self, equation: &str) -> Option<Polynomial> {
+        match Polynomial::new_with_mode(equation, self.mode) {
+            Ok(polynomial) => Some(polynomial),
+            Err(error) => {
+                display_error::display_error(equation, &error);
+                None
+            }
+        }
+    }
+
+    fn display_history(&self) {
+        for (index, line) in self.history.iter().enumerate() {
+            println!("{:>4}  {}", index + 1, line);
+        }
+    }
+}
+
+/// Read and run lines from the standard input until `:quit` or the end of the input.
+pub fn run(mode: ParseMode) {
+    let mut session: Session = Session::new(mode);
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    println!("{}", WELCOME);
+
+    loop {
+        print!("{}", PROMPT);
+        if io::stdout().flush().is_err() {
+            return;
+        }
+        match lines.next() {
+            Some(Ok(line)) => {
+                if !session.execute(&line) {
+                    return;
+                }
+            }
+            _ => {
+                println!();
+                return;
+            }
+        }
+    }
+}
+
+fn solved(mut polynomial: Polynomial) -> Polynomial {
+    if polynomial.solutions.is_none() {
+        polynomial.solve();
+    }
+    polynomial
+}
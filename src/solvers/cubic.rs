@@ -0,0 +1,360 @@
+use crate::constants::math_tools_constants::PI;
+use crate::math_tools::basic;
+use crate::math_tools::complex::Complex;
+use crate::math_tools::factorization::factorize;
+use crate::math_tools::polynomial::Polynomial;
+use crate::math_tools::rational::Rational;
+use crate::math_tools::root::Root;
+use crate::math_tools::solution::{collect_solutions, SolutionSet};
+use crate::solvers::derivation::{Derivation, Step};
+use crate::solvers::formula::{function, indexed, number, product, symbol, Formula, Relation};
+use crate::solvers::quadratic::solve_quadratic;
+
+/// Discriminant `-(4p^3 + 27q^2)` of the depressed cubic `t^3 + p * t + q`.
+pub fn discriminant(p: &Rational, q: &Rational) -> Rational {
+    -(p.pow(3) * Rational::from(4) + q.pow(2) * Rational::from(27))
+}
+
+/// Substituting `X = t - b / (3a)` removes the square term and leaves `t^3 + p * t + q`,
+/// whose discriminant has the sign of the cubic's one.
+pub fn depress(coefficients: &[Rational]) -> (Rational, Rational) {
+    let (a, b, c, d) = (
+        &coefficients[3],
+        &coefficients[2],
+        &coefficients[1],
+        &coefficients[0],
+    );
+    let p: Rational = (a * c * Rational::from(3) - b * b) / (a * a * Rational::from(3));
+    let q: Rational = (b.pow(3) * Rational::from(2) - a * b * c * Rational::from(9)
+        + a * a * d * Rational::from(27))
+        / (a.pow(3) * Rational::from(27));
+    (p, q)
+}
+
+pub fn solve_cubic(coefficients: &[Rational]) -> Derivation {
+    if coefficients.len() != 4 || coefficients[3].is_zero() {
+        panic!("Wrong solver used.")
+    }
+
+    let a: &Rational = &coefficients[3];
+    let b: &Rational = &coefficients[2];
+    let shift: Rational = -b / (a * Rational::from(3));
+
+    let (p, q) = depress(coefficients);
+    let delta: Rational = discriminant(&p, &q);
+    let substitution: Step = if b.is_zero() {
+        Step::statement(
+            "Cubic equation already depressed",
+            symbol("X").equals(symbol("t")),
+        )
+        .with_condition(symbol("b").equals(number(0)))
+    } else {
+        Step::computation(
+            "Substitution removing the square term",
+            symbol("X").equals(symbol("t") - shift_formula()),
+            None,
+            symbol("t").shifted_by(&shift),
+        )
+    };
+    let mut steps: Vec<Step> = vec![
+        substitution,
+        Step::statement(
+            "Depressed cubic",
+            (symbol("t").pow(3) + symbol("p") * symbol("t") + symbol("q")).equals(number(0)),
+        ),
+        Step::computation(
+            "",
+            symbol("p").equals(
+                (product(vec![number(3), symbol("a"), symbol("c")]) - symbol("b").pow(2))
+                    / product(vec![number(3), symbol("a").pow(2)]),
+            ),
+            None,
+            &p,
+        ),
+        Step::computation(
+            "",
+            symbol("q").equals(
+                (product(vec![number(2), symbol("b").pow(3)])
+                    - product(vec![number(9), symbol("a"), symbol("b"), symbol("c")])
+                    + product(vec![number(27), symbol("a").pow(2), symbol("d")]))
+                    / product(vec![number(27), symbol("a").pow(3)]),
+            ),
+            None,
+            &q,
+        ),
+        Step::computation(
+            "Discriminant",
+            symbol("∆").equals(
+                -(product(vec![number(4), symbol("p").pow(3)])
+                    + product(vec![number(27), symbol("q").pow(2)])),
+            ),
+            None,
+            &delta,
+        ),
+    ];
+
+    let rational_root: Option<Rational> = factorize(&Polynomial::from_coefficients(coefficients))
+        .roots
+        .into_iter()
+        .next()
+        .map(|(root, _)| root);
+
+    let roots: Vec<Root> = if delta.is_zero() {
+        compute_multiple_solutions(&p, &q, &shift, &mut steps)
+    } else if let Some(root) = rational_root {
+        compute_deflated_solutions(coefficients, root, &mut steps)
+    } else if delta.is_negative() {
+        compute_cardano_solutions(&p, &q, &shift, &mut steps)
+    } else {
+        compute_three_real_solutions(&p, &q, &shift, &mut steps)
+    };
+    Derivation::new(steps, SolutionSet::Finite(collect_solutions(roots)))
+}
+
+/// Largest real root of the cubic, computed without its derivation.
+pub fn largest_real_root(coefficients: &[Rational]) -> f64 {
+    let shift: Rational = -&coefficients[2] / (&coefficients[3] * Rational::from(3));
+    let (p, q) = depress(coefficients);
+    let delta: Rational = discriminant(&p, &q);
+
+    if delta.is_zero() && !p.is_zero() {
+        let simple: Rational = q * Rational::from(3) / &p;
+        let double: Rational = -&simple / Rational::from(2);
+        return (simple.max(double) + shift).to_f64();
+    }
+
+    let (p, q): (f64, f64) = (p.to_f64(), q.to_f64());
+    let largest: f64 = if delta.is_zero() || delta.is_negative() {
+        let (u, v): (f64, f64) = cardano_terms(p, q);
+        u + v
+    } else {
+        let (amplitude, theta): (f64, f64) = trigonometric_terms(p, q);
+        amplitude * basic::cosine(theta)
+    };
+    largest + shift.to_f64()
+}
+
+/// Private part
+fn compute_multiple_solutions(
+    p: &Rational,
+    q: &Rational,
+    shift: &Rational,
+    steps: &mut Vec<Step>,
+) -> Vec<Root> {
+    if p.is_zero() {
+        steps.push(Step::case(
+            Formula::And(vec![
+                symbol("∆").equals(number(0)),
+                symbol("p").equals(number(0)),
+            ]),
+            "One triple solution",
+        ));
+        steps.push(Step::computation(
+            "",
+            indexed("x", 0).equals(-shift_formula()),
+            None,
+            shift,
+        ));
+        return vec![Root::Exact(shift.clone()); 3];
+    }
+
+    let simple: Rational = q * Rational::from(3) / p + shift;
+    let double: Rational = -(q * Rational::from(3)) / (p * Rational::from(2)) + shift;
+    steps.push(Step::case(
+        symbol("∆").equals(number(0)),
+        "One simple solution and one double solution",
+    ));
+    steps.push(Step::computation(
+        "",
+        indexed("x", 1)
+            .equals(product(vec![number(3), symbol("q")]) / symbol("p") - shift_formula()),
+        None,
+        &simple,
+    ));
+    steps.push(Step::computation(
+        "",
+        indexed("x", 2).equals(
+            -product(vec![number(3), symbol("q")]) / product(vec![number(2), symbol("p")])
+                - shift_formula(),
+        ),
+        None,
+        &double,
+    ));
+    vec![
+        Root::Exact(simple),
+        Root::Exact(double.clone()),
+        Root::Exact(double),
+    ]
+}
+
+/// With a rational root `r`, dividing by `X - r` leaves a quadratic solved exactly.
+fn compute_deflated_solutions(
+    coefficients: &[Rational],
+    root: Rational,
+    steps: &mut Vec<Step>,
+) -> Vec<Root> {
+    let factor: Polynomial = Polynomial::from_coefficients(&[-&root, Rational::one()]);
+    let quotient: Polynomial = Polynomial::from_coefficients(coefficients)
+        .div_rem(&factor)
+        .0;
+    steps.push(Step::computation("Rational root", symbol("r"), None, &root));
+    steps.push(Step::statement(
+        "Quotient by X - r",
+        Formula::polynomial(&quotient).equals(number(0)),
+    ));
+
+    let quadratic: Derivation = solve_quadratic(&quotient.coefficients);
+    steps.extend(quadratic.steps);
+    let mut roots: Vec<Root> = vec![Root::Exact(root)];
+    if let SolutionSet::Finite(solutions) = quadratic.solutions {
+        for solution in solutions {
+            roots.extend(vec![solution.root; solution.multiplicity]);
+        }
+    }
+    roots
+}
+
+/// Cardano's formula `t = u + v`, with `u^3` and `v^3` the roots of `z^2 + q * z - p^3 / 27`.
+fn compute_cardano_solutions(
+    p: &Rational,
+    q: &Rational,
+    shift: &Rational,
+    steps: &mut Vec<Step>,
+) -> Vec<Root> {
+    let (p, q, shift): (f64, f64, f64) = (p.to_f64(), q.to_f64(), shift.to_f64());
+    let (u, v): (f64, f64) = cardano_terms(p, q);
+
+    let x1: f64 = u + v + shift;
+    let x2: Complex = Complex::new(
+        -(u + v) / 2.0 + shift,
+        basic::square_root(3.0) / 2.0 * (u - v).abs(),
+    );
+    steps.push(Step::case(
+        symbol("∆").relation(Relation::Less, number(0)),
+        "One real solution and two complex solutions (Cardano's method)",
+    ));
+    steps.push(Step::computation(
+        "",
+        symbol("u").equals(
+            (-symbol("q") / number(2))
+                .plus_minus(
+                    (symbol("q").pow(2) / number(4) + symbol("p").pow(3) / number(27)).sqrt(),
+                )
+                .cbrt(),
+        ),
+        None,
+        u,
+    ));
+    steps.push(Step::computation(
+        "",
+        symbol("v").equals(-symbol("p") / product(vec![number(3), symbol("u")])),
+        None,
+        v,
+    ));
+    steps.push(Step::computation(
+        "",
+        indexed("x", 1).equals(symbol("u") + symbol("v") - shift_formula()),
+        None,
+        x1,
+    ));
+    let complex_part = || {
+        product(vec![
+            symbol("i"),
+            number(3).sqrt(),
+            symbol("u") - symbol("v"),
+        ]) / number(2)
+    };
+    let real_part = || -(symbol("u") + symbol("v")) / number(2) - shift_formula();
+    steps.push(Step::computation(
+        "",
+        indexed("x", 2).equals(real_part() + complex_part()),
+        None,
+        x2,
+    ));
+    steps.push(Step::computation(
+        "",
+        indexed("x", 3).equals(real_part() - complex_part()),
+        None,
+        x2.conjugate(),
+    ));
+    vec![
+        Root::Approximate(x1),
+        Root::Complex(x2),
+        Root::Complex(x2.conjugate()),
+    ]
+}
+
+/// Viète's trigonometric form `t_k = 2√(-p/3) * cos(θ - 2πk/3)`, real when `p < 0`.
+fn compute_three_real_solutions(
+    p: &Rational,
+    q: &Rational,
+    shift: &Rational,
+    steps: &mut Vec<Step>,
+) -> Vec<Root> {
+    let (p, q, shift): (f64, f64, f64) = (p.to_f64(), q.to_f64(), shift.to_f64());
+    let (amplitude, theta): (f64, f64) = trigonometric_terms(p, q);
+
+    steps.push(Step::case(
+        symbol("∆").relation(Relation::Greater, number(0)),
+        "Three real solutions (trigonometric method)",
+    ));
+    steps.push(Step::computation(
+        "",
+        symbol("θ").equals(
+            function(
+                "acos",
+                product(vec![number(3), symbol("q")]) / product(vec![number(2), symbol("p")])
+                    * (-number(3) / symbol("p")).sqrt(),
+            ) / number(3),
+        ),
+        None,
+        theta,
+    ));
+    (0..3)
+        .map(|k| {
+            let solution: f64 =
+                amplitude * basic::cosine(theta - 2.0 * PI * k as f64 / 3.0) + shift;
+            let angle: Formula =
+                symbol("θ") - product(vec![number(2), symbol("π")]) * number(k as i64) / number(3);
+            steps.push(Step::computation(
+                "",
+                indexed("x", k + 1).equals(
+                    product(vec![number(2), (-symbol("p") / number(3)).sqrt()])
+                        * function("cos", angle)
+                        - shift_formula(),
+                ),
+                None,
+                solution,
+            ));
+            Root::Approximate(solution)
+        })
+        .collect()
+}
+
+/// `u` and `v` of Cardano's formula, real when `∆ <= 0`.
+fn cardano_terms(p: f64, q: f64) -> (f64, f64) {
+    let half_q: f64 = -q / 2.0;
+    let sqrt_term: f64 = basic::square_root(q * q / 4.0 + p * p * p / 27.0);
+
+    // Taking the cube root of the larger term and deducing the other from uv = -p / 3
+    // avoids cancelling two close values.
+    let u: f64 = if half_q < 0.0 {
+        basic::cube_root(half_q - sqrt_term)
+    } else {
+        basic::cube_root(half_q + sqrt_term)
+    };
+    let v: f64 = if p == 0.0 { 0.0 } else { -p / (3.0 * u) };
+    (u, v)
+}
+
+/// Amplitude `2√(-p/3)` and angle `θ` of the trigonometric form, real when `∆ > 0`.
+fn trigonometric_terms(p: f64, q: f64) -> (f64, f64) {
+    let amplitude: f64 = 2.0 * basic::square_root(-p / 3.0);
+    let cosine: f64 = (3.0 * q / (2.0 * p) * basic::square_root(-3.0 / p)).clamp(-1.0, 1.0);
+    (amplitude, basic::arc_cosine(cosine) / 3.0)
+}
+
+/// `b / (3a)`, subtracted from each root of the depressed cubic.
+fn shift_formula() -> Formula {
+    symbol("b") / product(vec![number(3), symbol("a")])
+}
@@ -0,0 +1,114 @@
+use crate::math_tools::solution::SolutionSet;
+use crate::solvers::formula::Formula;
+use std::fmt;
+
+/// One line of a derivation: a statement, or a formula with the coefficients substituted
+/// and the value it gives, possibly under a condition such as `∆ > 0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub condition: Option<Formula>,
+    pub description: String,
+    pub formula: Option<Formula>,
+    pub substitution: Option<Formula>,
+    pub result: Option<Formula>,
+    /// Plain text remark on the result, such as its error estimate.
+    pub remark: Option<String>,
+}
+
+/// Solutions found by a solver, with the steps that lead to them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Derivation {
+    pub steps: Vec<Step>,
+    pub solutions: SolutionSet,
+}
+
+impl Step {
+    /// Statement such as `Converged after 12 iterations`.
+    pub fn note(description: impl Into<String>) -> Self {
+        Step {
+            condition: None,
+            description: description.into(),
+            formula: None,
+            substitution: None,
+            result: None,
+            remark: None,
+        }
+    }
+
+    /// Statement following from a condition, as in `∆ > 0 => Two real solutions`.
+    pub fn case(condition: Formula, description: impl Into<String>) -> Self {
+        Step::note(description).with_condition(condition)
+    }
+
+    /// Formula given without a value, as in `Depressed cubic: t^3 + p * t + q = 0`.
+    pub fn statement(description: impl Into<String>, formula: Formula) -> Self {
+        Step {
+            formula: Some(formula),
+            ..Step::note(description)
+        }
+    }
+
+    /// Value computed from a formula such as `x0 = -b / (2a)`, along with the same formula
+    /// once the coefficients are substituted, when it is worth showing.
+    pub fn computation(
+        description: impl Into<String>,
+        formula: Formula,
+        substitution: Option<Formula>,
+        result: impl Into<Formula>,
+    ) -> Self {
+        Step {
+            substitution,
+            result: Some(result.into()),
+            ..Step::statement(description, formula)
+        }
+    }
+
+    pub fn with_condition(self, condition: Formula) -> Self {
+        Step {
+            condition: Some(condition),
+            ..self
+        }
+    }
+
+    pub fn with_remark(self, remark: impl Into<String>) -> Self {
+        Step {
+            remark: Some(remark.into()),
+            ..self
+        }
+    }
+
+    /// Formula, substitution and result, in this order and without the missing ones.
+    pub fn parts(&self) -> Vec<&Formula> {
+        let mut parts: Vec<&Formula> = Vec::new();
+        parts.extend(self.formula.as_ref());
+        parts.extend(self.substitution.as_ref());
+        parts.extend(self.result.as_ref());
+        parts
+    }
+}
+
+impl fmt::Display for Step {
+    /// `condition => description: formula = substitution = result (remark)`, without the
+    /// missing parts.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(condition) = &self.condition {
+            write!(f, "{} => ", condition)?;
+        }
+        let parts: Vec<String> = self.parts().iter().map(ToString::to_string).collect();
+        match (self.description.is_empty(), parts.is_empty()) {
+            (_, true) => write!(f, "{}", self.description)?,
+            (true, false) => write!(f, "{}", parts.join(" = "))?,
+            (false, false) => write!(f, "{}: {}", self.description, parts.join(" = "))?,
+        }
+        if let Some(remark) = &self.remark {
+            write!(f, " ({})", remark)?;
+        }
+        Ok(())
+    }
+}
+
+impl Derivation {
+    pub fn new(steps: Vec<Step>, solutions: SolutionSet) -> Self {
+        Derivation { steps, solutions }
+    }
+}
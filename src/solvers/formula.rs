@@ -0,0 +1,520 @@
+use crate::math_tools::big_int::BigInt;
+use crate::math_tools::complex::Complex;
+use crate::math_tools::polynomial::Polynomial;
+use crate::math_tools::rational::Rational;
+use crate::math_tools::root::Root;
+use crate::math_tools::surd::Surd;
+use crate::solvers::quadratic::complex_parts;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Expression of a derivation step, kept as a tree so that each display writes it in its
+/// own notation. The `Display` implementation gives the plain text one, as in
+/// `x1 = (-b - √∆) / (2a)`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Formula {
+    /// Letter such as `b`, `∆` or `θ`.
+    Symbol(String),
+    /// Letter with a numbered index, as in `x1`.
+    Indexed(String, usize),
+    Exact(Rational),
+    Approximate(f64),
+    /// Terms each added, subtracted or both, the first sign being written only when it is
+    /// not a plus, as in `-b ± √∆`.
+    Sum(Vec<(Sign, Formula)>),
+    /// Factors written next to each other, as in `4ac`.
+    Product(Vec<Formula>),
+    /// Factors separated by an explicit multiplication sign, as in `4 * 1 * (-6)`.
+    Times(Vec<Formula>),
+    Quotient(Box<Formula>, Box<Formula>),
+    Power(Box<Formula>, u32),
+    SquareRoot(Box<Formula>),
+    CubeRoot(Box<Formula>),
+    Function(String, Box<Formula>),
+    Relation(Box<Formula>, Relation, Box<Formula>),
+    /// Conditions holding together, as in `∆ = 0 and p = 0`.
+    And(Vec<Formula>),
+    /// Values given one after the other, as in `1, -1`.
+    List(Vec<Formula>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sign {
+    Plus,
+    Minus,
+    PlusMinus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    Equal,
+    NotEqual,
+    Less,
+    Greater,
+    GreaterOrEqual,
+}
+
+/// Place of a formula inside another one, which decides whether it is parenthesized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// Term of a sum, with its sign and whether it comes first.
+    Term(Sign, bool),
+    /// Factor of a `Product`.
+    Factor,
+    /// Factor of a `Times`.
+    ExplicitFactor,
+    Numerator,
+    Denominator,
+    Base,
+    Radicand,
+}
+
+pub fn symbol(name: &str) -> Formula {
+    Formula::Symbol(name.to_string())
+}
+
+pub fn indexed(name: &str, index: usize) -> Formula {
+    Formula::Indexed(name.to_string(), index)
+}
+
+pub fn number(value: i64) -> Formula {
+    Formula::Exact(Rational::from(value))
+}
+
+pub fn product(factors: Vec<Formula>) -> Formula {
+    Formula::Product(factors)
+}
+
+pub fn function(name: &str, argument: Formula) -> Formula {
+    Formula::Function(name.to_string(), Box::new(argument))
+}
+
+/// `±term`, or `term` preceded by `±` in a sum.
+pub fn plus_minus(term: Formula) -> Formula {
+    Formula::Sum(vec![(Sign::PlusMinus, term)])
+}
+
+impl Formula {
+    pub fn pow(self, exponent: u32) -> Formula {
+        Formula::Power(Box::new(self), exponent)
+    }
+
+    pub fn sqrt(self) -> Formula {
+        Formula::SquareRoot(Box::new(self))
+    }
+
+    pub fn cbrt(self) -> Formula {
+        Formula::CubeRoot(Box::new(self))
+    }
+
+    /// `self ± term`.
+    pub fn plus_minus(self, term: Formula) -> Formula {
+        self.with_term(Sign::PlusMinus, term)
+    }
+
+    /// `self + value`, or `self - |value|` for a negative value.
+    pub fn shifted_by(self, value: &Rational) -> Formula {
+        match value.is_negative() {
+            true => self - Formula::from(value.abs()),
+            false => self + Formula::from(value),
+        }
+    }
+
+    pub fn equals(self, other: Formula) -> Formula {
+        self.relation(Relation::Equal, other)
+    }
+
+    pub fn relation(self, relation: Relation, other: Formula) -> Formula {
+        Formula::Relation(Box::new(self), relation, Box::new(other))
+    }
+
+    /// `real + i * imaginary` with its approximate parts.
+    pub fn complex(value: Complex) -> Formula {
+        let imaginary: Formula = symbol("i") * Formula::Approximate(value.imaginary.abs());
+        let real: Formula = Formula::Approximate(value.real);
+        if value.imaginary < 0.0 {
+            real - imaginary
+        } else {
+            real + imaginary
+        }
+    }
+
+    /// Exact value of a solution, as in `1/3`, `(-3 + √17) / 4` or `(1 - i√2) / 3`, when
+    /// there is one: complex roots have it for quadratics only, from `complex_parts`.
+    pub fn exact_root(polynomial: &Polynomial, root: &Root) -> Option<Formula> {
+        match root {
+            Root::Exact(value) => Some(Formula::from(value)),
+            Root::Surd(value) => Some(Formula::from(value)),
+            Root::Complex(value) if polynomial.degree == 2 => {
+                let (real, imaginary): (Rational, Root) = complex_parts(&polynomial.coefficients);
+                let (modulus, radicand): (Rational, BigInt) = match imaginary {
+                    Root::Exact(modulus) => (modulus, BigInt::one()),
+                    Root::Surd(modulus) => (
+                        Rational::new(modulus.coefficient, modulus.denominator),
+                        modulus.radicand,
+                    ),
+                    _ => return None,
+                };
+                let denominator: BigInt = real.denominator().lcm(modulus.denominator());
+                let constant: BigInt = real.numerator() * &(&denominator / real.denominator());
+                let coefficient: BigInt =
+                    modulus.numerator() * &(&denominator / modulus.denominator());
+                let sign: Sign = match value.imaginary < 0.0 {
+                    true => Sign::Minus,
+                    false => Sign::Plus,
+                };
+                Some(Formula::over(
+                    constant,
+                    sign,
+                    coefficient,
+                    Some(symbol("i")),
+                    radicand,
+                    denominator,
+                ))
+            }
+            Root::Approximate(_) | Root::Complex(_) => None,
+        }
+    }
+
+    /// Terms by decreasing power of `X`, as in `X^3 - 2 * X + 1/3`.
+    pub fn polynomial(polynomial: &Polynomial) -> Formula {
+        let mut terms: Vec<(Sign, Formula)> = Vec::new();
+        for (power, coefficient) in polynomial.coefficients.iter().enumerate().rev() {
+            if coefficient.is_zero() && !(power == 0 && terms.is_empty()) {
+                continue;
+            }
+            let variable: Formula = match power {
+                1 => symbol("X"),
+                _ => symbol("X").pow(power as u32),
+            };
+            let magnitude: Rational = coefficient.abs();
+            let term: Formula = match (power, magnitude == Rational::one()) {
+                (0, _) => Formula::Exact(magnitude),
+                (_, true) => variable,
+                (_, false) => Formula::Exact(magnitude) * variable,
+            };
+            let sign: Sign = match coefficient.is_negative() {
+                true => Sign::Minus,
+                false => Sign::Plus,
+            };
+            terms.push((sign, term));
+        }
+        Formula::Sum(terms)
+    }
+
+    /// Whether the formula is written starting with a minus sign, or a `±` one.
+    pub fn is_negative(&self) -> bool {
+        match self {
+            Formula::Exact(value) => value.is_negative(),
+            Formula::Approximate(value) => value.is_sign_negative(),
+            Formula::Sum(terms) => terms.len() == 1 && terms[0].0 != Sign::Plus,
+            Formula::Quotient(numerator, _) => {
+                numerator.is_negative() && !numerator.needs_parentheses(Position::Numerator)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the formula is parenthesized at this position in the plain text notation,
+    /// where nothing but parentheses groups a numerator, a denominator or a radicand.
+    pub fn needs_parentheses(&self, position: Position) -> bool {
+        let negative: bool = self.is_negative();
+        let sum: bool = matches!(self, Formula::Sum(terms) if terms.len() > 1);
+        let fraction: bool = matches!(self, Formula::Exact(value) if value.to_decimal().is_none());
+        let loose: bool = matches!(
+            self,
+            Formula::Relation(..) | Formula::And(_) | Formula::List(_)
+        );
+
+        match position {
+            Position::Term(sign, first) => {
+                let leading: bool = first && sign == Sign::Plus;
+                loose || (!leading && (sum || negative))
+            }
+            Position::Factor => {
+                loose
+                    || sum
+                    || negative
+                    || fraction
+                    || matches!(self, Formula::Quotient(..) | Formula::Times(_))
+            }
+            Position::ExplicitFactor => loose || sum || negative || fraction,
+            Position::Numerator => {
+                loose || sum || fraction || matches!(self, Formula::Quotient(..))
+            }
+            Position::Denominator => !self.is_single() && !self.is_block(),
+            Position::Base => !self.is_single() && !matches!(self, Formula::Function(..)),
+            // `√-∆` needs no parentheses.
+            Position::Radicand => match self {
+                Formula::Sum(terms) if terms.len() == 1 && terms[0].0 == Sign::Minus => {
+                    !terms[0].1.is_single()
+                }
+                _ => !self.is_single(),
+            },
+        }
+    }
+
+    // Private part
+    /// `(constant ± coefficient * unit * √radicand) / denominator`, each part written only
+    /// when it is not neutral.
+    fn over(
+        constant: BigInt,
+        sign: Sign,
+        coefficient: BigInt,
+        unit: Option<Formula>,
+        radicand: BigInt,
+        denominator: BigInt,
+    ) -> Formula {
+        let mut factors: Vec<Formula> = Vec::new();
+        if !coefficient.is_one() || (unit.is_none() && radicand.is_one()) {
+            factors.push(Formula::from(Rational::from(coefficient)));
+        }
+        factors.extend(unit);
+        if !radicand.is_one() {
+            factors.push(Formula::from(Rational::from(radicand)).sqrt());
+        }
+        let term: Formula = match factors.len() {
+            1 => factors.remove(0),
+            _ => product(factors),
+        };
+
+        let numerator: Formula = match (constant.is_zero(), sign) {
+            (true, Sign::Plus) => term,
+            (true, sign) => Formula::Sum(vec![(sign, term)]),
+            (false, sign) => Formula::Sum(vec![
+                (Sign::Plus, Formula::from(Rational::from(constant))),
+                (sign, term),
+            ]),
+        };
+        match denominator.is_one() {
+            true => numerator,
+            false => numerator / Formula::from(Rational::from(denominator)),
+        }
+    }
+
+    fn with_term(self, sign: Sign, term: Formula) -> Formula {
+        match self {
+            Formula::Sum(mut terms) if !terms.is_empty() => {
+                terms.push((sign, term));
+                Formula::Sum(terms)
+            }
+            first => Formula::Sum(vec![(Sign::Plus, first), (sign, term)]),
+        }
+    }
+
+    /// A letter or a number written without sign nor fraction bar.
+    fn is_single(&self) -> bool {
+        match self {
+            Formula::Symbol(_) | Formula::Indexed(..) => true,
+            Formula::Exact(value) => !value.is_negative() && value.to_decimal().is_some(),
+            Formula::Approximate(value) => !value.is_sign_negative(),
+            _ => false,
+        }
+    }
+
+    /// Written as one block, such as `b^2`, `√∆` or `cos(θ)`.
+    fn is_block(&self) -> bool {
+        matches!(
+            self,
+            Formula::Power(..)
+                | Formula::SquareRoot(_)
+                | Formula::CubeRoot(_)
+                | Formula::Function(..)
+        )
+    }
+
+    fn write_at(&self, f: &mut fmt::Formatter<'_>, position: Position) -> fmt::Result {
+        if self.needs_parentheses(position) {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let relation: &str = match self {
+            Relation::Equal => "=",
+            Relation::NotEqual => "≠",
+            Relation::Less => "<",
+            Relation::Greater => ">",
+            Relation::GreaterOrEqual => ">=",
+        };
+        write!(f, "{}", relation)
+    }
+}
+
+impl fmt::Display for Formula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Formula::Symbol(name) => write!(f, "{}", name),
+            Formula::Indexed(name, index) => write!(f, "{}{}", name, index),
+            Formula::Exact(value) => write!(f, "{}", value),
+            Formula::Approximate(value) => write!(f, "{}", value),
+            Formula::Sum(terms) => {
+                for (index, (sign, term)) in terms.iter().enumerate() {
+                    let text: &str = match (index, sign) {
+                        (0, Sign::Plus) => "",
+                        (0, Sign::Minus) => "-",
+                        (0, Sign::PlusMinus) => "±",
+                        (_, Sign::Plus) => " + ",
+                        (_, Sign::Minus) => " - ",
+                        (_, Sign::PlusMinus) => " ± ",
+                    };
+                    write!(f, "{}", text)?;
+                    term.write_at(f, Position::Term(*sign, index == 0))?;
+                }
+                Ok(())
+            }
+            Formula::Product(factors) => {
+                for factor in factors {
+                    factor.write_at(f, Position::Factor)?;
+                }
+                Ok(())
+            }
+            Formula::Times(factors) => {
+                for (index, factor) in factors.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " * ")?;
+                    }
+                    factor.write_at(f, Position::ExplicitFactor)?;
+                }
+                Ok(())
+            }
+            Formula::Quotient(numerator, denominator) => {
+                numerator.write_at(f, Position::Numerator)?;
+                write!(f, " / ")?;
+                denominator.write_at(f, Position::Denominator)
+            }
+            Formula::Power(base, exponent) => {
+                base.write_at(f, Position::Base)?;
+                write!(f, "^{}", exponent)
+            }
+            Formula::SquareRoot(radicand) => {
+                write!(f, "√")?;
+                radicand.write_at(f, Position::Radicand)
+            }
+            Formula::CubeRoot(radicand) => {
+                write!(f, "∛")?;
+                radicand.write_at(f, Position::Radicand)
+            }
+            Formula::Function(name, argument) => write!(f, "{}({})", name, argument),
+            Formula::Relation(left, relation, right) => {
+                write!(f, "{} {} {}", left, relation, right)
+            }
+            Formula::And(conditions) => write!(f, "{}", join(conditions, " and ")),
+            Formula::List(values) => write!(f, "{}", join(values, ", ")),
+        }
+    }
+}
+
+impl From<Rational> for Formula {
+    fn from(value: Rational) -> Self {
+        Formula::Exact(value)
+    }
+}
+
+impl From<&Rational> for Formula {
+    fn from(value: &Rational) -> Self {
+        Formula::Exact(value.clone())
+    }
+}
+
+/// `(constant ± coefficient√radicand) / denominator`, as in `(-3 + √17) / 4`.
+impl From<&Surd> for Formula {
+    fn from(value: &Surd) -> Self {
+        let sign: Sign = match value.coefficient.is_negative() {
+            true => Sign::Minus,
+            false => Sign::Plus,
+        };
+        Formula::over(
+            value.constant.clone(),
+            sign,
+            value.coefficient.abs(),
+            None,
+            value.radicand.clone(),
+            value.denominator.clone(),
+        )
+    }
+}
+
+impl From<f64> for Formula {
+    fn from(value: f64) -> Self {
+        Formula::Approximate(value)
+    }
+}
+
+impl From<Complex> for Formula {
+    fn from(value: Complex) -> Self {
+        Formula::complex(value)
+    }
+}
+
+impl From<&Root> for Formula {
+    fn from(root: &Root) -> Self {
+        match root {
+            Root::Exact(value) => Formula::from(value),
+            Root::Complex(value) => Formula::complex(*value),
+            _ => Formula::Approximate(root.to_f64()),
+        }
+    }
+}
+
+impl From<&[Root]> for Formula {
+    fn from(roots: &[Root]) -> Self {
+        Formula::List(roots.iter().map(Formula::from).collect())
+    }
+}
+
+impl Add for Formula {
+    type Output = Formula;
+
+    fn add(self, rhs: Formula) -> Formula {
+        self.with_term(Sign::Plus, rhs)
+    }
+}
+
+impl Sub for Formula {
+    type Output = Formula;
+
+    fn sub(self, rhs: Formula) -> Formula {
+        self.with_term(Sign::Minus, rhs)
+    }
+}
+
+/// Explicit multiplication, as in `4 * a`.
+impl Mul for Formula {
+    type Output = Formula;
+
+    fn mul(self, rhs: Formula) -> Formula {
+        match self {
+            Formula::Times(mut factors) => {
+                factors.push(rhs);
+                Formula::Times(factors)
+            }
+            first => Formula::Times(vec![first, rhs]),
+        }
+    }
+}
+
+impl Div for Formula {
+    type Output = Formula;
+
+    fn div(self, rhs: Formula) -> Formula {
+        Formula::Quotient(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl Neg for Formula {
+    type Output = Formula;
+
+    fn neg(self) -> Formula {
+        Formula::Sum(vec![(Sign::Minus, self)])
+    }
+}
+
+fn join(formulas: &[Formula], separator: &str) -> String {
+    let formulas: Vec<String> = formulas.iter().map(Formula::to_string).collect();
+    formulas.join(separator)
+}
@@ -1,20 +1,42 @@
-pub fn solve_linear(coefficients: &[f64]) -> Option<Vec<f64>> {
-    if coefficients.len() < 2 {
-        return None;
-    }
+use crate::math_tools::rational::Rational;
+use crate::math_tools::root::Root;
+use crate::math_tools::solution::{collect_solutions, SolutionSet};
+use crate::solvers::derivation::{Derivation, Step};
+use crate::solvers::formula::{symbol, Formula};
 
-    let a: f64 = coefficients[1];
-    let b: f64 = coefficients[0];
+/// Also solves the constant equations left when every power of X cancels out.
+pub fn solve_linear(coefficients: &[Rational]) -> Derivation {
+    let zero: Rational = Rational::zero();
+    let a: &Rational = coefficients.get(1).unwrap_or(&zero);
+    let b: &Rational = coefficients.first().unwrap_or(&zero);
 
-    if a == 0.0 {
-        println!("Linear equation with no solution (a = 0).");
-        None
-    } else if b == 0.0 {
-        Some(vec![0.0])
+    if a.is_zero() && b.is_zero() {
+        Derivation::new(
+            vec![Step::case(
+                Formula::from(&zero).equals(Formula::from(&zero)),
+                "True for any value of X",
+            )],
+            SolutionSet::Identity,
+        )
+    } else if a.is_zero() {
+        Derivation::new(
+            vec![Step::case(
+                Formula::from(b).equals(Formula::from(&zero)),
+                "False for any value of X",
+            )],
+            SolutionSet::Contradiction,
+        )
     } else {
-        let solution: f64 = -b / a;
-        dbg!(&solution);
-        println!("Linear equation with one solution: -b/a = {:.}/{:.}", -b, a);
-        Some(vec![solution])
+        let solution: Rational = -b / a;
+        let steps: Vec<Step> = vec![Step::computation(
+            "Linear equation with one solution",
+            symbol("x").equals(-symbol("b") / symbol("a")),
+            Some(Formula::from(-b) / Formula::from(a)),
+            &solution,
+        )];
+        Derivation::new(
+            steps,
+            SolutionSet::Finite(collect_solutions(vec![Root::Exact(solution)])),
+        )
     }
 }
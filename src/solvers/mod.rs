@@ -1,2 +1,9 @@
+pub mod cubic;
+pub mod derivation;
+pub mod formula;
 pub mod linear;
+pub mod numerical;
 pub mod quadratic;
+pub mod quartic;
+pub mod registry;
+pub mod solver;
@@ -0,0 +1,236 @@
+use crate::constants::math_tools_constants::{
+    NO_CONVERGENCE, PI, POLISHING_STEPS, ROOT_FINDING_MAX_ITERATIONS,
+};
+use crate::math_tools::basic;
+use crate::math_tools::complex::Complex;
+use crate::math_tools::factorization::square_free_factors;
+use crate::math_tools::polynomial::Polynomial;
+use crate::math_tools::rational::Rational;
+use crate::math_tools::root::Root;
+use crate::math_tools::solution::{collect_solutions, SolutionSet};
+use crate::solvers::derivation::{Derivation, Step};
+use crate::solvers::formula::{indexed, product, symbol, Formula};
+
+/// Roots found by `find_roots`, each with a radius around it that contains an exact root.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumericalRoots {
+    pub roots: Vec<Complex>,
+    pub errors: Vec<f64>,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+/// Every complex root of the polynomial with `f64` coefficients, found by the
+/// Aberth–Ehrlich simultaneous iteration and refined by a few Newton steps.
+pub fn find_roots(coefficients: &[f64]) -> NumericalRoots {
+    let degree: usize = coefficients.len() - 1;
+    let mut roots: Vec<Complex> = initial_guesses(coefficients);
+    let mut converged: Vec<bool> = vec![false; degree];
+    let mut iterations: usize = 0;
+
+    while converged.contains(&false) && iterations < ROOT_FINDING_MAX_ITERATIONS {
+        iterations += 1;
+        for k in 0..degree {
+            if converged[k] {
+                continue;
+            }
+            let (value, derivative, bound) = evaluate(coefficients, roots[k]);
+            // |p(z)| below the rounding error of its evaluation: z is as good as it gets.
+            if value.modulus() <= 4.0 * f64::EPSILON * bound {
+                converged[k] = true;
+                continue;
+            }
+
+            let ratio: Complex = value / derivative;
+            let repulsion: Complex = (0..degree)
+                .filter(|&j| j != k)
+                .map(|j| Complex::from(1.0) / (roots[k] - roots[j]))
+                .fold(Complex::zero(), |sum, term| sum + term);
+            let correction: Complex = ratio / (Complex::from(1.0) - ratio * repulsion);
+
+            roots[k] = roots[k] - correction;
+            converged[k] = correction.modulus() <= f64::EPSILON * roots[k].modulus();
+        }
+    }
+
+    let roots: Vec<Complex> = roots
+        .into_iter()
+        .map(|root| polish(coefficients, root))
+        .collect();
+    let errors: Vec<f64> = roots
+        .iter()
+        .map(|root| error_estimate(coefficients, *root))
+        .collect();
+    NumericalRoots {
+        roots,
+        errors,
+        iterations,
+        converged: !converged.contains(&false),
+    }
+}
+
+pub fn solve_numerical(coefficients: &[Rational]) -> Derivation {
+    if coefficients.len() < 2 || coefficients[coefficients.len() - 1].is_zero() {
+        panic!("Wrong solver used.")
+    }
+
+    let mut steps: Vec<Step> = vec![Step::note(
+        "Numerical method (Aberth–Ehrlich iterations, then Newton polishing)",
+    )];
+    let mut solutions: Vec<Root> = Vec::new();
+    let zeros: usize = coefficients.iter().take_while(|c| c.is_zero()).count();
+    if zeros > 0 {
+        steps.push(Step::statement(
+            "Zero is a solution, its factor divided out",
+            symbol("X").pow(zeros as u32),
+        ));
+        solutions.extend(vec![Root::Exact(Rational::zero()); zeros]);
+    }
+
+    let remaining: Polynomial = Polynomial::from_coefficients(&coefficients[zeros..]);
+    let factors: Vec<(Polynomial, usize)> = square_free_factors(&remaining);
+    if factors.iter().any(|(_, multiplicity)| *multiplicity > 1) {
+        let decomposition: Vec<Formula> = factors
+            .iter()
+            .map(|(factor, multiplicity)| match multiplicity {
+                1 => Formula::polynomial(factor),
+                _ => Formula::polynomial(factor).pow(*multiplicity as u32),
+            })
+            .collect();
+        steps.push(Step::statement(
+            "Square-free decomposition, each factor having simple roots",
+            product(decomposition),
+        ));
+    }
+
+    let mut count: usize = 0;
+    for (factor, multiplicity) in &factors {
+        let roots: Vec<(Root, Option<f64>)> = match solve_square_free(factor, &mut steps) {
+            Ok(roots) => roots,
+            Err(reason) => return Derivation::new(steps, SolutionSet::Unsupported { reason }),
+        };
+        for (root, error) in roots {
+            count += 1;
+            let mut step: Step =
+                Step::computation("", indexed("x", count), None, Formula::from(&root));
+            if let Some(error) = error {
+                step = step.with_remark(format!("error estimate: {:.1e}", error));
+            }
+            steps.push(step);
+            solutions.extend(vec![root; *multiplicity]);
+        }
+    }
+    Derivation::new(steps, SolutionSet::Finite(collect_solutions(solutions)))
+}
+
+// Private part
+/// Roots of a polynomial without repeated roots, exact for a linear one, otherwise with
+/// their error estimates.
+fn solve_square_free(
+    factor: &Polynomial,
+    steps: &mut Vec<Step>,
+) -> Result<Vec<(Root, Option<f64>)>, String> {
+    if factor.degree == 1 {
+        let root: Rational = -&factor.coefficients[0] / &factor.coefficients[1];
+        return Ok(vec![(Root::Exact(root), None)]);
+    }
+
+    let coefficients: Vec<f64> = factor.coefficients.iter().map(Rational::to_f64).collect();
+    let result: NumericalRoots = find_roots(&coefficients);
+    if !result.converged {
+        return Err(format!(
+            "{}{} iterations",
+            NO_CONVERGENCE, result.iterations
+        ));
+    }
+    steps.push(Step::note(format!(
+        "Converged after {} iterations",
+        result.iterations
+    )));
+
+    let roots: Vec<(Root, Option<f64>)> = result
+        .roots
+        .iter()
+        .zip(&result.errors)
+        .map(|(root, error)| {
+            // A root whose disc of uncertainty meets the real axis is taken as real.
+            let solution: Root = if root.imaginary.abs() <= *error {
+                Root::Approximate(root.real)
+            } else {
+                Root::Complex(*root)
+            };
+            (solution, Some(*error))
+        })
+        .collect();
+    Ok(roots)
+}
+
+/// Points spread on a circle around the centroid of the roots, whose radius is
+/// Fujiwara's bound on their modulus. The angle offset keeps them off the real axis and
+/// breaks the conjugate symmetry.
+fn initial_guesses(coefficients: &[f64]) -> Vec<Complex> {
+    let degree: usize = coefficients.len() - 1;
+    let leading: f64 = coefficients[degree];
+    let center: f64 = -coefficients[degree - 1] / (degree as f64 * leading);
+
+    let radius: f64 = (0..degree)
+        .map(|i| {
+            let ratio: f64 = (coefficients[i] / leading).abs();
+            let ratio: f64 = if i == 0 { ratio / 2.0 } else { ratio };
+            2.0 * basic::nth_root(ratio, (degree - i) as u32)
+        })
+        .fold(0.0, f64::max);
+
+    (0..degree)
+        .map(|k| {
+            let angle: f64 = 2.0 * PI * k as f64 / degree as f64 + PI / (2.0 * degree as f64);
+            Complex::new(
+                center + radius * basic::cosine(angle),
+                radius * basic::sine(angle),
+            )
+        })
+        .collect()
+}
+
+/// `p(z)`, `p'(z)` and `Σ|a_i| * |z|^i` with Horner's scheme, the last one bounding the
+/// rounding error of the first.
+fn evaluate(coefficients: &[f64], z: Complex) -> (Complex, Complex, f64) {
+    let modulus: f64 = z.modulus();
+    let mut value: Complex = Complex::zero();
+    let mut derivative: Complex = Complex::zero();
+    let mut bound: f64 = 0.0;
+
+    for coefficient in coefficients.iter().rev() {
+        derivative = derivative * z + value;
+        value = value * z + Complex::from(*coefficient);
+        bound = bound * modulus + coefficient.abs();
+    }
+    (value, derivative, bound)
+}
+
+fn polish(coefficients: &[f64], root: Complex) -> Complex {
+    let mut root: Complex = root;
+    let (mut value, mut derivative, _) = evaluate(coefficients, root);
+
+    for _ in 0..POLISHING_STEPS {
+        if derivative == Complex::zero() {
+            break;
+        }
+        let next_root: Complex = root - value / derivative;
+        let (next_value, next_derivative, _) = evaluate(coefficients, next_root);
+        if next_value.modulus() >= value.modulus() {
+            break;
+        }
+        (root, value, derivative) = (next_root, next_value, next_derivative);
+    }
+    root
+}
+
+/// A disc of radius `n * |p(z) / p'(z)|` around `z` contains a root, `|p(z)|` being at
+/// least the rounding error of its evaluation.
+fn error_estimate(coefficients: &[f64], root: Complex) -> f64 {
+    let degree: f64 = (coefficients.len() - 1) as f64;
+    let (value, derivative, bound) = evaluate(coefficients, root);
+    let residual: f64 = value.modulus().max(f64::EPSILON * bound);
+    degree * residual / derivative.modulus()
+}
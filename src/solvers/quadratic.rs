@@ -1,60 +1,153 @@
 use crate::math_tools::basic;
+use crate::math_tools::complex::Complex;
+use crate::math_tools::rational::Rational;
+use crate::math_tools::root::Root;
+use crate::math_tools::solution::{collect_solutions, SolutionSet};
+use crate::math_tools::surd::Surd;
+use crate::solvers::derivation::{Derivation, Step};
+use crate::solvers::formula::{indexed, number, product, symbol, Formula, Relation};
 
-pub fn solve_quadratic(coefficients: &[f64]) -> Option<Vec<f64>> {
-    if coefficients.len() != 3 || coefficients[2] == 0.0 {
+pub fn discriminant(coefficients: &[Rational]) -> Rational {
+    let (a, b, c) = (&coefficients[2], &coefficients[1], &coefficients[0]);
+    b * b - a * c * Rational::from(4)
+}
+
+/// Exact real part `-b / (2a)` and imaginary modulus `√-∆ / |2a|` of the complex roots,
+/// for a negative discriminant.
+pub fn complex_parts(coefficients: &[Rational]) -> (Rational, Root) {
+    let two_a: Rational = &coefficients[2] * Rational::from(2);
+    let minus_delta: Rational = -discriminant(coefficients);
+    let factor: Rational = two_a.abs().recip();
+
+    let imaginary: Root = match minus_delta.sqrt() {
+        Some(sqrt_delta) => Root::Exact(sqrt_delta * factor),
+        None => Root::Surd(
+            Surd::new(&Rational::zero(), &factor, &minus_delta)
+                .expect("-∆ is not a perfect square"),
+        ),
+    };
+    (-&coefficients[1] / &two_a, imaginary)
+}
+
+pub fn solve_quadratic(coefficients: &[Rational]) -> Derivation {
+    if coefficients.len() != 3 || coefficients[2].is_zero() {
         panic!("Wrong solver used.")
     }
 
-    let a: f64 = coefficients[2];
-    let b: f64 = coefficients[1];
-    let c: f64 = coefficients[0];
-
-    let delta: f64 = b * b - (a * c * 4.0);
-    println!(
-        "Quadratic equation with discriminant ∆ = {:.}^2 - 4 * {:.} * {:.} = {:.}",
-        b, a, c, delta
-    );
+    let a: &Rational = &coefficients[2];
+    let b: &Rational = &coefficients[1];
+    let c: &Rational = &coefficients[0];
 
-    let sqrt_delta: f64 = basic::square_root(delta.abs());
+    let delta: Rational = discriminant(coefficients);
+    let mut steps: Vec<Step> = vec![Step::computation(
+        "Quadratic equation with discriminant",
+        symbol("∆").equals(symbol("b").pow(2) - product(vec![number(4), symbol("a"), symbol("c")])),
+        Some(Formula::from(b).pow(2) - number(4) * Formula::from(a) * Formula::from(c)),
+        &delta,
+    )];
 
-    if delta == 0.0 {
-        compute_single_solution(a, b)
-    } else if delta > 0.0 {
-        compute_two_real_solutions(a, b, c, sqrt_delta)
+    let roots: Vec<Root> = if delta.is_zero() {
+        compute_single_solution(a, b, &mut steps)
+    } else if !delta.is_negative() {
+        compute_two_real_solutions(a, b, &delta, &mut steps)
     } else {
-        compute_two_complex_solutions(a, b, sqrt_delta)
-    }
+        compute_two_complex_solutions(a, b, &delta, &mut steps)
+    };
+    Derivation::new(steps, SolutionSet::Finite(collect_solutions(roots)))
+}
+
+/// Private part
+fn compute_single_solution(a: &Rational, b: &Rational, steps: &mut Vec<Step>) -> Vec<Root> {
+    let x0: Rational = -b / (a * Rational::from(2));
+    steps.push(Step::case(
+        discriminant_sign(Relation::Equal),
+        "One single solution",
+    ));
+    steps.push(Step::computation(
+        "",
+        indexed("x", 0).equals(-symbol("b") / double_a()),
+        Some(Formula::from(-b) / (number(2) * Formula::from(a))),
+        &x0,
+    ));
+    vec![Root::Exact(x0.clone()), Root::Exact(x0)]
+}
+
+fn compute_two_real_solutions(
+    a: &Rational,
+    b: &Rational,
+    delta: &Rational,
+    steps: &mut Vec<Step>,
+) -> Vec<Root> {
+    let two_a: Rational = a * Rational::from(2);
+    let (x1, x2): (Root, Root) = match delta.sqrt() {
+        Some(sqrt_delta) => (
+            Root::Exact((-b - &sqrt_delta) / &two_a),
+            Root::Exact((-b + &sqrt_delta) / &two_a),
+        ),
+        None => {
+            let x2: Surd = Surd::new(&(-b / &two_a), &two_a.recip(), delta)
+                .expect("∆ is not a perfect square");
+            (Root::Surd(x2.conjugate()), Root::Surd(x2))
+        }
+    };
+    steps.push(Step::case(
+        discriminant_sign(Relation::Greater),
+        "Two real solutions",
+    ));
+    let root_of_delta = || symbol("∆").sqrt();
+    steps.push(Step::computation(
+        "",
+        indexed("x", 1).equals((-symbol("b") - root_of_delta()) / double_a()),
+        Some((Formula::from(-b) - root_of_delta()) / (number(2) * Formula::from(a))),
+        x1.to_f64(),
+    ));
+    steps.push(Step::computation(
+        "",
+        indexed("x", 2).equals((-symbol("b") + root_of_delta()) / double_a()),
+        Some((Formula::from(-b) + root_of_delta()) / (number(2) * Formula::from(a))),
+        x2.to_f64(),
+    ));
+    vec![x1, x2]
+}
+
+fn compute_two_complex_solutions(
+    a: &Rational,
+    b: &Rational,
+    delta: &Rational,
+    steps: &mut Vec<Step>,
+) -> Vec<Root> {
+    let two_a: Rational = a * Rational::from(2);
+    let real_part: f64 = (-b / &two_a).to_f64();
+    let imaginary_part: f64 = match (-delta).sqrt() {
+        Some(sqrt_delta) => (sqrt_delta / &two_a).to_f64(),
+        None => basic::square_root((-delta).to_f64()) / two_a.to_f64(),
+    };
+    let x1: Complex = Complex::new(real_part, imaginary_part);
+    steps.push(Step::case(
+        discriminant_sign(Relation::Less),
+        "Two complex solutions",
+    ));
+    let root_of_delta = || product(vec![symbol("i"), (-symbol("∆")).sqrt()]);
+    steps.push(Step::computation(
+        "",
+        indexed("x", 1).equals((-symbol("b") + root_of_delta()) / double_a()),
+        Some((Formula::from(-b) + root_of_delta()) / (number(2) * Formula::from(a))),
+        x1,
+    ));
+    steps.push(Step::computation(
+        "",
+        indexed("x", 2).equals((-symbol("b") - root_of_delta()) / double_a()),
+        Some((Formula::from(-b) - root_of_delta()) / (number(2) * Formula::from(a))),
+        x1.conjugate(),
+    ));
+    vec![Root::Complex(x1), Root::Complex(x1.conjugate())]
+}
+
+/// `∆ = 0`, `∆ > 0` or `∆ < 0`.
+fn discriminant_sign(relation: Relation) -> Formula {
+    symbol("∆").relation(relation, number(0))
 }
 
-fn compute_single_solution(a: f64, b: f64) -> Option<Vec<f64>> {
-    let x0: f64 = -b / (2.0 * a);
-    println!(
-        "∆ = 0 => One single solution: x0 = {:.} / (2 * {:.}) = {:.}",
-        -b, a, x0
-    );
-    Some(vec![x0])
-}
-
-fn compute_two_real_solutions(a: f64, b: f64, c: f64, sqrt_delta: f64) -> Option<Vec<f64>> {
-    let x1: f64 = (-b - sqrt_delta) / (2.0 * a);
-    let x2: f64 = (2.0 * c) / (-b - sqrt_delta);
-    println!("∆ > 0 => Two real solutions:");
-    println!("x1 = ({:.} - √∆) / (2 * {:.}) = {:.}", -b, a, x1);
-    println!("x2 = ({:.} + √∆) / (2 * {:.}) = {:.}", -b, a, x2);
-    Some(vec![x1, x2])
-}
-
-fn compute_two_complex_solutions(a: f64, b: f64, sqrt_delta: f64) -> Option<Vec<f64>> {
-    let real_part: f64 = -b / (2.0 * a);
-    let imaginary_part: f64 = sqrt_delta / (2.0 * a);
-    println!("∆ < 0 => Two complex solutions:");
-    println!(
-        "x1 = ({:.} - i√-∆) / (2 * {:.}) = {:.} + i * {:.}",
-        -b, a, real_part, imaginary_part
-    );
-    println!(
-        "x2 = ({:.} + i√-∆) / (2 * {:.}) = {:.} - i * {:.}",
-        -b, a, real_part, imaginary_part
-    );
-    Some(vec![real_part, imaginary_part])
+fn double_a() -> Formula {
+    product(vec![number(2), symbol("a")])
 }
@@ -0,0 +1,394 @@
+use crate::math_tools::basic;
+use crate::math_tools::complex::Complex;
+use crate::math_tools::factorization::{factorize, Factorization};
+use crate::math_tools::polynomial::Polynomial;
+use crate::math_tools::rational::Rational;
+use crate::math_tools::root::Root;
+use crate::math_tools::solution::{collect_solutions, SolutionSet};
+use crate::math_tools::surd::Surd;
+use crate::solvers::cubic;
+use crate::solvers::derivation::{Derivation, Step};
+use crate::solvers::formula::{number, plus_minus, product, symbol, Formula, Relation, Sign};
+use crate::solvers::quadratic::solve_quadratic;
+
+/// Substituting `X = y - b / (4a)` removes the cubic term and leaves
+/// `y^4 + p * y^2 + q * y + r`.
+pub fn depress(coefficients: &[Rational]) -> (Rational, Rational, Rational) {
+    let (a, b, c, d, e) = (
+        &coefficients[4],
+        &coefficients[3],
+        &coefficients[2],
+        &coefficients[1],
+        &coefficients[0],
+    );
+    let p: Rational =
+        (a * c * Rational::from(8) - b * b * Rational::from(3)) / (a * a * Rational::from(8));
+    let q: Rational = (b.pow(3) - a * b * c * Rational::from(4) + a * a * d * Rational::from(8))
+        / (a.pow(3) * Rational::from(8));
+    let r: Rational = (a.pow(3) * e * Rational::from(256) - a * a * b * d * Rational::from(64)
+        + a * b * b * c * Rational::from(16)
+        - b.pow(4) * Rational::from(3))
+        / (a.pow(4) * Rational::from(256));
+    (p, q, r)
+}
+
+pub fn solve_quartic(coefficients: &[Rational]) -> Derivation {
+    if coefficients.len() != 5 || coefficients[4].is_zero() {
+        panic!("Wrong solver used.")
+    }
+
+    let a: &Rational = &coefficients[4];
+    let b: &Rational = &coefficients[3];
+    let shift: Rational = -b / (a * Rational::from(4));
+
+    let (p, q, r) = depress(coefficients);
+    let substitution: Step = if b.is_zero() {
+        Step::statement(
+            "Quartic equation already depressed",
+            symbol("X").equals(symbol("y")),
+        )
+        .with_condition(symbol("b").equals(number(0)))
+    } else {
+        Step::computation(
+            "Substitution removing the cubic term",
+            symbol("X").equals(symbol("y") - shift_formula()),
+            None,
+            symbol("y").shifted_by(&shift),
+        )
+    };
+    let mut steps: Vec<Step> = vec![
+        substitution,
+        Step::statement(
+            "Depressed quartic",
+            (symbol("y").pow(4)
+                + symbol("p") * symbol("y").pow(2)
+                + symbol("q") * symbol("y")
+                + symbol("r"))
+            .equals(number(0)),
+        ),
+        Step::computation(
+            "",
+            symbol("p").equals(
+                (product(vec![number(8), symbol("a"), symbol("c")])
+                    - product(vec![number(3), symbol("b").pow(2)]))
+                    / product(vec![number(8), symbol("a").pow(2)]),
+            ),
+            None,
+            &p,
+        ),
+        Step::computation(
+            "",
+            symbol("q").equals(
+                (symbol("b").pow(3)
+                    - product(vec![number(4), symbol("a"), symbol("b"), symbol("c")])
+                    + product(vec![number(8), symbol("a").pow(2), symbol("d")]))
+                    / product(vec![number(8), symbol("a").pow(3)]),
+            ),
+            None,
+            &q,
+        ),
+        Step::computation(
+            "",
+            symbol("r").equals(
+                (product(vec![number(256), symbol("a").pow(3), symbol("e")])
+                    - product(vec![
+                        number(64),
+                        symbol("a").pow(2),
+                        symbol("b"),
+                        symbol("d"),
+                    ])
+                    + product(vec![
+                        number(16),
+                        symbol("a"),
+                        symbol("b").pow(2),
+                        symbol("c"),
+                    ])
+                    - product(vec![number(3), symbol("b").pow(4)]))
+                    / product(vec![number(256), symbol("a").pow(4)]),
+            ),
+            None,
+            &r,
+        ),
+    ];
+
+    let factorization: Factorization = factorize(&Polynomial::from_coefficients(coefficients));
+
+    let roots: Vec<Root> = if q.is_zero() {
+        compute_biquadratic_solutions(&p, &r, &shift, &mut steps)
+    } else if !factorization.roots.is_empty() {
+        compute_deflated_solutions(factorization, &mut steps)
+    } else {
+        compute_ferrari_solutions(&p, &q, &r, &shift, &mut steps)
+    };
+    Derivation::new(steps, SolutionSet::Finite(collect_solutions(roots)))
+}
+
+// Private part
+/// With `q = 0`, `z = y^2` solves the quadratic `z^2 + p * z + r`.
+fn compute_biquadratic_solutions(
+    p: &Rational,
+    r: &Rational,
+    shift: &Rational,
+    steps: &mut Vec<Step>,
+) -> Vec<Root> {
+    let delta: Rational = p * p - r * Rational::from(4);
+    steps.push(
+        Step::statement(
+            "Biquadratic equation",
+            Formula::List(vec![
+                symbol("z").equals(symbol("y").pow(2)),
+                (symbol("z").pow(2) + symbol("p") * symbol("z") + symbol("r")).equals(number(0)),
+            ]),
+        )
+        .with_condition(symbol("q").equals(number(0))),
+    );
+    steps.push(Step::computation(
+        "Discriminant",
+        symbol("∆").equals(symbol("p").pow(2) - product(vec![number(4), symbol("r")])),
+        None,
+        &delta,
+    ));
+
+    if delta.is_negative() {
+        let sqrt_delta: f64 = basic::square_root((-delta).to_f64());
+        let z: Complex = Complex::new((-p).to_f64() / 2.0, sqrt_delta / 2.0);
+        steps.push(
+            Step::computation(
+                "",
+                symbol("z").equals(
+                    (-symbol("p")).plus_minus(product(vec![symbol("i"), (-symbol("∆")).sqrt()]))
+                        / number(2),
+                ),
+                None,
+                Formula::List(vec![Formula::from(z), Formula::from(z.conjugate())]),
+            )
+            .with_condition(symbol("∆").relation(Relation::Less, number(0))),
+        );
+
+        let y: Complex = z.sqrt();
+        let shift: f64 = shift.to_f64();
+        let solutions: Vec<Root> = [y, -y, y.conjugate(), -y.conjugate()]
+            .into_iter()
+            .map(|y| Root::Complex(Complex::new(y.real + shift, y.imaginary)))
+            .collect();
+        steps.push(solutions_step(biquadratic_solutions(), &solutions));
+        return solutions;
+    }
+
+    let solutions: Vec<Root> = match delta.sqrt() {
+        Some(sqrt_delta) => {
+            let z1: Rational = (-p - &sqrt_delta) / Rational::from(2);
+            let z2: Rational = (-p + &sqrt_delta) / Rational::from(2);
+            steps.push(
+                Step::computation(
+                    "",
+                    square_roots_of_z(),
+                    None,
+                    Formula::List(vec![Formula::from(&z1), Formula::from(&z2)]),
+                )
+                .with_condition(symbol("∆").relation(Relation::GreaterOrEqual, number(0))),
+            );
+            [z1, z2]
+                .iter()
+                .flat_map(|z| exact_square_roots(z, shift))
+                .collect()
+        }
+        None => {
+            let sqrt_delta: f64 = basic::square_root(delta.to_f64());
+            let (z1, z2): (f64, f64) = (
+                ((-p).to_f64() - sqrt_delta) / 2.0,
+                ((-p).to_f64() + sqrt_delta) / 2.0,
+            );
+            steps.push(
+                Step::computation(
+                    "",
+                    square_roots_of_z(),
+                    None,
+                    Formula::List(vec![Formula::from(z1), Formula::from(z2)]),
+                )
+                .with_condition(symbol("∆").relation(Relation::Greater, number(0))),
+            );
+            [z1, z2]
+                .into_iter()
+                .flat_map(|z| approximate_square_roots(z, shift.to_f64()))
+                .collect()
+        }
+    };
+    steps.push(solutions_step(biquadratic_solutions(), &solutions));
+    solutions
+}
+
+/// Both `shift ± √z`, exact when `z` is a non-negative rational.
+fn exact_square_roots(z: &Rational, shift: &Rational) -> Vec<Root> {
+    if z.is_negative() {
+        return approximate_square_roots(z.to_f64(), shift.to_f64());
+    }
+    match z.sqrt() {
+        Some(root) => vec![Root::Exact(shift - &root), Root::Exact(shift + &root)],
+        None => {
+            let root: Surd =
+                Surd::new(shift, &Rational::one(), z).expect("z is not a perfect square");
+            vec![Root::Surd(root.conjugate()), Root::Surd(root)]
+        }
+    }
+}
+
+fn approximate_square_roots(z: f64, shift: f64) -> Vec<Root> {
+    if z < 0.0 {
+        let root: Complex = Complex::new(shift, basic::square_root(-z));
+        vec![Root::Complex(root), Root::Complex(root.conjugate())]
+    } else {
+        let root: f64 = basic::square_root(z);
+        vec![
+            Root::Approximate(shift - root),
+            Root::Approximate(shift + root),
+        ]
+    }
+}
+
+/// Each rational root divided out exactly, the quadratic or cubic left being solved by
+/// its own exact method.
+fn compute_deflated_solutions(factorization: Factorization, steps: &mut Vec<Step>) -> Vec<Root> {
+    let mut roots: Vec<Root> = Vec::new();
+    for (root, multiplicity) in factorization.roots {
+        let step: Step = Step::computation("Rational root", symbol("X"), None, &root);
+        steps.push(match multiplicity {
+            1 => step,
+            multiplicity => step.with_remark(format!("multiplicity {}", multiplicity)),
+        });
+        roots.extend(vec![Root::Exact(root); multiplicity]);
+    }
+
+    let remaining: Polynomial = factorization.remaining;
+    if remaining.degree == 0 {
+        return roots;
+    }
+    steps.push(Step::statement(
+        "Quotient by the rational root factors",
+        Formula::polynomial(&remaining).equals(number(0)),
+    ));
+    // Without a rational root left, the quotient has no linear factor.
+    let derivation: Derivation = match remaining.degree {
+        2 => solve_quadratic(&remaining.coefficients),
+        _ => cubic::solve_cubic(&remaining.coefficients),
+    };
+    steps.extend(derivation.steps);
+    if let SolutionSet::Finite(solutions) = derivation.solutions {
+        for solution in solutions {
+            roots.extend(vec![solution.root; solution.multiplicity]);
+        }
+    }
+    roots
+}
+
+/// Ferrari's method: for `m` a positive root of the resolvent cubic, the depressed quartic
+/// is the difference of two squares `(y^2 + p / 2 + m)^2 - (√(2m) * y - q / (2√(2m)))^2`.
+fn compute_ferrari_solutions(
+    p: &Rational,
+    q: &Rational,
+    r: &Rational,
+    shift: &Rational,
+    steps: &mut Vec<Step>,
+) -> Vec<Root> {
+    let resolvent: Vec<Rational> = vec![
+        -(q * q),
+        p * p * Rational::from(2) - r * Rational::from(8),
+        p * Rational::from(8),
+        Rational::from(8),
+    ];
+    let m: f64 = cubic::largest_real_root(&resolvent);
+    steps.push(Step::case(
+        symbol("q").relation(Relation::NotEqual, number(0)),
+        "Ferrari's method with the resolvent cubic",
+    ));
+    steps.push(Step::statement(
+        "Resolvent cubic",
+        (product(vec![number(8), symbol("m").pow(3)])
+            + product(vec![number(8), symbol("p")]) * symbol("m").pow(2)
+            + (product(vec![number(2), symbol("p").pow(2)])
+                - product(vec![number(8), symbol("r")]))
+                * symbol("m")
+            - symbol("q").pow(2))
+        .equals(number(0)),
+    ));
+    steps.push(Step::computation("Largest real root", symbol("m"), None, m));
+
+    let (p, q, shift): (f64, f64, f64) = (p.to_f64(), q.to_f64(), shift.to_f64());
+    let sqrt_two_m: f64 = basic::square_root(2.0 * m);
+    let constant: f64 = p / 2.0 + m;
+    let correction: f64 = q / (2.0 * sqrt_two_m);
+
+    let mut solutions: Vec<Root> = quadratic_solutions(-sqrt_two_m, constant + correction, shift);
+    steps.push(
+        solutions_step(
+            symbol("X").equals(symbol("y") - shift_formula()),
+            &solutions,
+        )
+        .with_condition(ferrari_quadratic(Sign::Minus)),
+    );
+    let second: Vec<Root> = quadratic_solutions(sqrt_two_m, constant - correction, shift);
+    steps.push(
+        solutions_step(symbol("X").equals(symbol("y") - shift_formula()), &second)
+            .with_condition(ferrari_quadratic(Sign::Plus)),
+    );
+    solutions.extend(second);
+    solutions
+}
+
+/// Both `shift + y` for the roots `y` of the monic quadratic `y^2 + b * y + c`.
+fn quadratic_solutions(b: f64, c: f64, shift: f64) -> Vec<Root> {
+    let delta: f64 = b * b - 4.0 * c;
+
+    if delta < 0.0 {
+        let root: Complex = Complex::new(-b / 2.0 + shift, basic::square_root(-delta) / 2.0);
+        return vec![Root::Complex(root), Root::Complex(root.conjugate())];
+    }
+    // The root of larger magnitude comes first, the other from the product of the roots.
+    let sqrt_delta: f64 = basic::square_root(delta);
+    let y1: f64 = if b < 0.0 {
+        (-b + sqrt_delta) / 2.0
+    } else {
+        (-b - sqrt_delta) / 2.0
+    };
+    let y2: f64 = if y1 == 0.0 { 0.0 } else { c / y1 };
+    vec![Root::Approximate(y1 + shift), Root::Approximate(y2 + shift)]
+}
+
+fn solutions_step(formula: Formula, solutions: &[Root]) -> Step {
+    Step::computation("", formula, None, solutions)
+}
+
+/// `z = (-p ± √∆) / 2`.
+fn square_roots_of_z() -> Formula {
+    symbol("z").equals((-symbol("p")).plus_minus(symbol("∆").sqrt()) / number(2))
+}
+
+/// `X = ±√z - b / (4a)`.
+fn biquadratic_solutions() -> Formula {
+    symbol("X").equals(plus_minus(symbol("z").sqrt()) - shift_formula())
+}
+
+/// `y^2 - √(2m) * y + p / 2 + m + q / (2√(2m)) = 0`, or its counterpart with the signs
+/// of the odd terms swapped when `sign` is a plus.
+fn ferrari_quadratic(sign: Sign) -> Formula {
+    let root: Formula = product(vec![number(2), symbol("m")]).sqrt();
+    let linear: Formula = root.clone() * symbol("y");
+    let correction: Formula = symbol("q") / product(vec![number(2), root]);
+    let (linear_sign, correction_sign): (Sign, Sign) = match sign {
+        Sign::Plus => (Sign::Plus, Sign::Minus),
+        _ => (Sign::Minus, Sign::Plus),
+    };
+    Formula::Sum(vec![
+        (Sign::Plus, symbol("y").pow(2)),
+        (linear_sign, linear),
+        (Sign::Plus, symbol("p") / number(2)),
+        (Sign::Plus, symbol("m")),
+        (correction_sign, correction),
+    ])
+    .equals(number(0))
+}
+
+/// `b / (4a)`, subtracted from each root of the depressed quartic.
+fn shift_formula() -> Formula {
+    symbol("b") / product(vec![number(4), symbol("a")])
+}
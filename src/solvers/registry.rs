@@ -0,0 +1,61 @@
+use crate::math_tools::rational::Rational;
+use crate::solvers::solver::{
+    CubicSolver, LinearSolver, NumericalSolver, QuadraticSolver, QuarticSolver, Solver,
+};
+
+/// Solvers tried from the most recently registered one, so that a registered solver
+/// takes precedence over the ones it was added to.
+pub struct SolverRegistry {
+    solvers: Vec<Box<dyn Solver>>,
+}
+
+impl SolverRegistry {
+    /// Closed forms up to degree 4, the numerical method above.
+    pub fn new() -> Self {
+        let mut registry: SolverRegistry = SolverRegistry::empty();
+        registry.register(Box::new(NumericalSolver));
+        registry.register(Box::new(LinearSolver));
+        registry.register(Box::new(QuadraticSolver));
+        registry.register(Box::new(CubicSolver));
+        registry.register(Box::new(QuarticSolver));
+        registry
+    }
+
+    /// No solver, for callers registering their own ones only.
+    pub fn empty() -> Self {
+        SolverRegistry {
+            solvers: Vec::new(),
+        }
+    }
+
+    /// The numerical method for every degree but 0.
+    pub fn numerical() -> Self {
+        let mut registry: SolverRegistry = SolverRegistry::empty();
+        registry.register(Box::new(LinearSolver));
+        registry.register(Box::new(NumericalSolver));
+        registry
+    }
+
+    pub fn register(&mut self, solver: Box<dyn Solver>) -> &mut Self {
+        self.solvers.push(solver);
+        self
+    }
+
+    pub fn find(&self, coefficients: &[Rational]) -> Option<&dyn Solver> {
+        self.solvers
+            .iter()
+            .rev()
+            .find(|solver| solver.can_solve(coefficients))
+            .map(|solver| solver.as_ref())
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.solvers.iter().map(|solver| solver.name()).collect()
+    }
+}
+
+impl Default for SolverRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
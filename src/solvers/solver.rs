@@ -0,0 +1,100 @@
+use crate::math_tools::rational::Rational;
+use crate::solvers::cubic::solve_cubic;
+use crate::solvers::derivation::Derivation;
+use crate::solvers::linear::solve_linear;
+use crate::solvers::numerical::solve_numerical;
+use crate::solvers::quadratic::solve_quadratic;
+use crate::solvers::quartic::solve_quartic;
+
+/// Strategy solving the polynomial equations of some degrees or shapes. The coefficients
+/// are given from the constant term up, without trailing zeros except for the zero
+/// polynomial.
+pub trait Solver {
+    fn name(&self) -> &str;
+
+    /// Whether `solve` handles these coefficients, `solve` being free to panic otherwise.
+    fn can_solve(&self, coefficients: &[Rational]) -> bool;
+
+    fn solve(&self, coefficients: &[Rational]) -> Derivation;
+}
+
+pub struct LinearSolver;
+pub struct QuadraticSolver;
+pub struct CubicSolver;
+pub struct QuarticSolver;
+pub struct NumericalSolver;
+
+impl Solver for LinearSolver {
+    fn name(&self) -> &str {
+        "linear"
+    }
+
+    fn can_solve(&self, coefficients: &[Rational]) -> bool {
+        coefficients.len() <= 2
+    }
+
+    fn solve(&self, coefficients: &[Rational]) -> Derivation {
+        solve_linear(coefficients)
+    }
+}
+
+impl Solver for QuadraticSolver {
+    fn name(&self) -> &str {
+        "quadratic"
+    }
+
+    fn can_solve(&self, coefficients: &[Rational]) -> bool {
+        has_degree(coefficients, 2)
+    }
+
+    fn solve(&self, coefficients: &[Rational]) -> Derivation {
+        solve_quadratic(coefficients)
+    }
+}
+
+impl Solver for CubicSolver {
+    fn name(&self) -> &str {
+        "cubic"
+    }
+
+    fn can_solve(&self, coefficients: &[Rational]) -> bool {
+        has_degree(coefficients, 3)
+    }
+
+    fn solve(&self, coefficients: &[Rational]) -> Derivation {
+        solve_cubic(coefficients)
+    }
+}
+
+impl Solver for QuarticSolver {
+    fn name(&self) -> &str {
+        "quartic"
+    }
+
+    fn can_solve(&self, coefficients: &[Rational]) -> bool {
+        has_degree(coefficients, 4)
+    }
+
+    fn solve(&self, coefficients: &[Rational]) -> Derivation {
+        solve_quartic(coefficients)
+    }
+}
+
+impl Solver for NumericalSolver {
+    fn name(&self) -> &str {
+        "numerical"
+    }
+
+    fn can_solve(&self, coefficients: &[Rational]) -> bool {
+        coefficients.len() >= 2 && coefficients.last().is_some_and(|c| !c.is_zero())
+    }
+
+    fn solve(&self, coefficients: &[Rational]) -> Derivation {
+        solve_numerical(coefficients)
+    }
+}
+
+/// Private part
+fn has_degree(coefficients: &[Rational], degree: usize) -> bool {
+    coefficients.len() == degree + 1 && !coefficients[degree].is_zero()
+}
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use computorv1::math_tools::basic::square_root;
+    use computorv1::math_tools::basic::{
+        arc_cosine, cosine, cube_root, nth_root, sine, square_root,
+    };
+    use std::f64::consts::PI;
 
     #[test]
     fn test_square_root_positive_values() {
@@ -17,4 +20,43 @@ mod tests {
     fn test_square_root_negative_value() {
         square_root(-1.0);
     }
+
+    #[test]
+    fn test_cube_root() {
+        assert_eq!(cube_root(27.0), 3.0);
+        assert_eq!(cube_root(-8.0), -2.0);
+        assert_eq!(cube_root(0.0), 0.0);
+        assert_eq!(cube_root(1e30), 1e10);
+        assert!((cube_root(2.0) - 1.2599210498948732).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_nth_root() {
+        assert_eq!(nth_root(32.0, 5), 2.0);
+        assert_eq!(nth_root(7.0, 1), 7.0);
+        assert_eq!(nth_root(0.0, 4), 0.0);
+        assert!((nth_root(0.5, 7) - 0.5f64.powf(1.0 / 7.0)).abs() < 1e-15);
+        assert!((nth_root(1e100, 10) - 1e10).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_trigonometry() {
+        let angles = [0.0, 0.5, 1.0, PI / 3.0, 2.0, PI, -4.0, 10.0];
+
+        for angle in angles {
+            assert!(
+                (cosine(angle) - angle.cos()).abs() < 1e-14,
+                "cos({})",
+                angle
+            );
+            assert!((sine(angle) - angle.sin()).abs() < 1e-14, "sin({})", angle);
+        }
+        for value in [-1.0, -0.5, 0.0, 0.3, 0.99, 1.0] {
+            assert!(
+                (arc_cosine(value) - f64::acos(value)).abs() < 1e-14,
+                "acos({})",
+                value
+            );
+        }
+    }
 }
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::batch::batch_runner::{run_batch, BatchSummary};
+    use computorv1::math_tools::polynomial::Polynomial;
+    use computorv1::parser::ParseMode;
+    use std::io::Cursor;
+
+    fn parses(mode: ParseMode) -> impl FnMut(usize, &str) -> bool {
+        move |_, equation| Polynomial::new_with_mode(equation, mode).is_ok()
+    }
+
+    #[test]
+    fn test_run_batch() {
+        let input: &str = "# exercises\nX^2 - 1 = 0\n\nX^2 = 2 * X^\n2 * X = 4\n  \n1 = 2\n";
+        let mut lines: Vec<(usize, String)> = Vec::new();
+        let summary: BatchSummary = run_batch(Cursor::new(input), |line, equation| {
+            lines.push((line, equation.to_string()));
+            Polynomial::new(equation).is_ok()
+        });
+
+        assert_eq!(
+            lines,
+            vec![
+                (2, "X^2 - 1 = 0".to_string()),
+                (4, "X^2 = 2 * X^".to_string()),
+                (5, "2 * X = 4".to_string()),
+                (7, "1 = 2".to_string()),
+            ]
+        );
+        assert_eq!(
+            summary,
+            BatchSummary {
+                solved: 3,
+                failed: vec![4]
+            }
+        );
+        assert!(!summary.is_success());
+        assert_eq!(summary.to_string(), "3 solved, 1 failed (line 4)");
+    }
+
+    #[test]
+    fn test_run_batch_failures() {
+        let input: &str = "x² = 1\nX^2 = 1\n";
+        let summary: BatchSummary = run_batch(Cursor::new(input), parses(ParseMode::Strict));
+        assert_eq!(summary.failed, vec![1]);
+
+        let summary: BatchSummary = run_batch(Cursor::new(input), parses(ParseMode::Natural));
+        assert!(summary.is_success());
+        assert_eq!(summary.to_string(), "2 solved, 0 failed");
+
+        let summary: BatchSummary = run_batch(Cursor::new("X = \n(X\r\nX = 1"), |_, _| false);
+        assert_eq!(summary.failed, vec![1, 2, 3]);
+        assert_eq!(summary.to_string(), "0 solved, 3 failed (lines 1, 2, 3)");
+
+        let input: &[u8] = b"X = 1\n\xff * X = 2\n";
+        let summary: BatchSummary = run_batch(input, parses(ParseMode::Strict));
+        assert_eq!(summary.failed, vec![2]);
+    }
+}
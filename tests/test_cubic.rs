@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::math_tools::complex::Complex;
+    use computorv1::math_tools::rational::Rational;
+    use computorv1::math_tools::root::Root;
+    use computorv1::math_tools::solution::{Solution, SolutionSet};
+    use computorv1::solvers::cubic::solve_cubic;
+    use computorv1::solvers::derivation::Step;
+
+    const EPSILON: f64 = 1e-12;
+
+    fn rationals(values: &[i64]) -> Vec<Rational> {
+        values.iter().map(|value| Rational::from(*value)).collect()
+    }
+
+    fn roots(coefficients: &[i64]) -> Vec<Root> {
+        match solve_cubic(&rationals(coefficients)).solutions {
+            SolutionSet::Finite(solutions) => solutions
+                .into_iter()
+                .map(|solution| solution.root)
+                .collect(),
+            other => panic!("Expected finite solutions, but got {:?}.", other),
+        }
+    }
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!(
+            (value - expected).abs() < EPSILON,
+            "Expected {}, but got {}.",
+            expected,
+            value
+        );
+    }
+
+    #[test]
+    fn test_three_real_solutions() {
+        let expected: [f64; 3] = [-1.5320888862379562, -0.3472963553338607, 1.8793852415718169];
+        let solutions: Vec<Root> = roots(&[-1, -3, 0, 1]);
+
+        assert_eq!(solutions.len(), 3);
+        for (solution, expected) in solutions.iter().zip(expected) {
+            assert!(matches!(solution, Root::Approximate(_)));
+            assert_close(solution.to_f64(), expected);
+        }
+    }
+
+    #[test]
+    fn test_rational_roots() {
+        let exact = |numerator: i64, denominator: i64| -> Solution {
+            Solution::new(
+                Root::Exact(Rational::new(numerator.into(), denominator.into())),
+                1,
+            )
+        };
+        let polynomials: Vec<(Vec<i64>, Vec<Solution>)> = vec![
+            (
+                vec![-6, 11, -6, 1],
+                vec![exact(1, 1), exact(2, 1), exact(3, 1)],
+            ),
+            (
+                vec![0, -1, 0, 1],
+                vec![exact(-1, 1), exact(0, 1), exact(1, 1)],
+            ),
+            (
+                vec![6, 1, -4, 1],
+                vec![exact(-1, 1), exact(2, 1), exact(3, 1)],
+            ),
+            (
+                vec![2, -3, -3, 2],
+                vec![exact(-1, 1), exact(1, 2), exact(2, 1)],
+            ),
+        ];
+
+        for (poly, expected) in polynomials {
+            assert_eq!(
+                solve_cubic(&rationals(&poly)).solutions,
+                SolutionSet::Finite(expected),
+                "Unexpected solutions for {:?}.",
+                poly
+            );
+        }
+
+        assert_eq!(
+            roots(&[0, 1, 0, 1]),
+            vec![
+                Root::Exact(Rational::zero()),
+                Root::Complex(Complex::new(0.0, 1.0)),
+                Root::Complex(Complex::new(0.0, -1.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_one_real_solution() {
+        let polynomials: Vec<(Vec<i64>, f64, Complex)> = vec![
+            (vec![-8, 0, 0, 1], 2.0, Complex::new(-1.0, 3f64.sqrt())),
+            (vec![1, 1, 1, 1], -1.0, Complex::new(0.0, 1.0)),
+            (
+                vec![-5, 1, 0, 2],
+                1.234772825053297,
+                Complex::new(-0.6173864125266485, 1.281989838929145),
+            ),
+        ];
+
+        for (poly, real, complex) in polynomials {
+            match roots(&poly).as_slice() {
+                [x1, Root::Complex(x2), Root::Complex(x3)] => {
+                    assert_close(x1.to_f64(), real);
+                    assert_close(x2.real, complex.real);
+                    assert_close(x2.imaginary, complex.imaginary);
+                    assert_eq!(*x3, x2.conjugate());
+                }
+                other => panic!(
+                    "Expected one real and two complex solutions, but got {:?}.",
+                    other
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiple_solutions() {
+        let exact = |value: Rational, multiplicity: usize| -> Solution {
+            Solution::new(Root::Exact(value), multiplicity)
+        };
+        let quarter: Rational = Rational::new(1.into(), 4.into());
+        let polynomials: Vec<(Vec<i64>, Vec<Solution>)> = vec![
+            (
+                vec![2, -3, 0, 1],
+                vec![exact(Rational::from(-2), 1), exact(Rational::one(), 2)],
+            ),
+            (vec![-1, 3, -3, 1], vec![exact(Rational::one(), 3)]),
+            (vec![0, 0, 0, 5], vec![exact(Rational::zero(), 3)]),
+            (
+                vec![-1, 2, 7, 4],
+                vec![exact(Rational::from(-1), 2), exact(quarter, 1)],
+            ),
+        ];
+
+        for (poly, expected) in polynomials {
+            assert_eq!(
+                solve_cubic(&rationals(&poly)).solutions,
+                SolutionSet::Finite(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_substitution_step() {
+        let substitution = |coefficients: &[i64]| -> Vec<String> {
+            let steps: Vec<Step> = solve_cubic(&rationals(coefficients)).steps;
+            steps[..2].iter().map(|step| step.to_string()).collect()
+        };
+        assert_eq!(
+            substitution(&[-1, -3, 0, 1]),
+            [
+                "b = 0 => Cubic equation already depressed: X = t",
+                "Depressed cubic: t^3 + p * t + q = 0"
+            ]
+        );
+        assert_eq!(
+            substitution(&[-1, 1, 2, -1]),
+            [
+                "Substitution removing the square term: X = t - b / (3a) = t + 2/3",
+                "Depressed cubic: t^3 + p * t + q = 0"
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Wrong solver used.")]
+    fn test_panic_on_zero_a() {
+        solve_cubic(&rationals(&[1, 2, 3, 0]));
+    }
+}
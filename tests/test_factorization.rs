@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::math_tools::factorization::{factorize, square_free_factors, Factorization};
+    use computorv1::math_tools::polynomial::Polynomial;
+    use computorv1::math_tools::rational::Rational;
+
+    fn factored_form(equation: &str) -> String {
+        factorize(&Polynomial::new(equation).unwrap()).to_string()
+    }
+
+    #[test]
+    fn test_rational_roots() {
+        let factorization: Factorization =
+            factorize(&Polynomial::new("2*X^4 + 5*X^3 - X^2 + 5*X - 3 = 0").unwrap());
+        assert_eq!(factorization.constant, Rational::from(2));
+        assert_eq!(
+            factorization.roots,
+            vec![
+                (Rational::from(-3), 1),
+                (Rational::new(1.into(), 2.into()), 1)
+            ]
+        );
+        assert_eq!(factorization.remaining, Polynomial::new("X^2 + 1").unwrap());
+        assert_eq!(factorization.to_string(), "2(X + 3)(X - 1/2)(X^2 + 1)");
+    }
+
+    #[test]
+    fn test_multiplicities_and_zero_roots() {
+        assert_eq!(factored_form("X^3 - 3*X + 2"), "(X + 2)(X - 1)^2");
+        assert_eq!(factored_form("X - X^3"), "-X(X + 1)(X - 1)");
+        assert_eq!(factored_form("X^5 - X^3"), "X^3(X + 1)(X - 1)");
+        assert_eq!(factored_form("3 * X^2"), "3X^2");
+    }
+
+    #[test]
+    fn test_rational_coefficients() {
+        assert_eq!(factored_form("X/2 - 1/3"), "1/2(X - 2/3)");
+        assert_eq!(factored_form("X^2 - 1/4"), "(X + 1/2)(X - 1/2)");
+        assert_eq!(
+            factored_form("0.5 * X^2 + 0.5 * X - 1"),
+            "1/2(X + 2)(X - 1)"
+        );
+    }
+
+    #[test]
+    fn test_irreducible() {
+        assert_eq!(factored_form("X^2 + X + 1"), "X^2 + X + 1");
+        assert_eq!(factored_form("2 * X^2 - 4"), "2(X^2 - 2)");
+        assert_eq!(factored_form("X^3 - 2*X + 1/2"), "X^3 - 2*X + 1/2");
+        assert_eq!(factored_form("7"), "7");
+        assert_eq!(factored_form("X = X"), "0");
+    }
+
+    #[test]
+    fn test_candidate_limits() {
+        assert_eq!(
+            factored_form("1000000 * X^2 - 1"),
+            "1000000(X + 1/1000)(X - 1/1000)"
+        );
+        // Too many divisors, or a divisor above the limit, leave the polynomial whole.
+        assert_eq!(
+            factored_form("735134400 * X^2 + X + 735134400"),
+            "735134400(X^2 + 1/735134400*X + 1)"
+        );
+        assert_eq!(factored_form("X - 10000000000"), "X - 10000000000");
+    }
+
+    #[test]
+    fn test_square_free_factors() {
+        let factors: Vec<(Polynomial, usize)> =
+            square_free_factors(&Polynomial::new("2 * (X - 1)^3 * (X^2 + 1) * X^2").unwrap());
+        assert_eq!(
+            factors,
+            vec![
+                (Polynomial::new("X^2 + 1").unwrap(), 1),
+                (Polynomial::new("X").unwrap(), 2),
+                (Polynomial::new("X - 1").unwrap(), 3),
+            ]
+        );
+
+        let factors: Vec<(Polynomial, usize)> =
+            square_free_factors(&Polynomial::new("X^3 - 2*X + 1").unwrap());
+        assert_eq!(
+            factors,
+            vec![(Polynomial::new("X^3 - 2*X + 1").unwrap(), 1)]
+        );
+        assert!(square_free_factors(&Polynomial::new("5").unwrap()).is_empty());
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::display::display_html::{
+        error_section, formula_to_mathml, polynomial_section, polynomial_to_html,
+        reduced_form_to_mathml,
+    };
+    use computorv1::math_tools::polynomial::Polynomial;
+    use computorv1::solvers::formula::{indexed, number, product, symbol, Formula, Relation};
+
+    fn solved_section(equation: &str) -> String {
+        let mut polynomial: Polynomial = Polynomial::new(equation).unwrap();
+        polynomial.solve();
+        polynomial_section(equation, &polynomial)
+    }
+
+    #[test]
+    fn test_reduced_form() {
+        let polynomial: Polynomial = Polynomial::new("3 * X^2 - 2 * X + 1 = 0").unwrap();
+        assert_eq!(
+            reduced_form_to_mathml(&polynomial),
+            "<mn>3</mn><mo>&#x2062;</mo><msup><mi>x</mi><mn>2</mn></msup>\
+             <mo>−</mo><mn>2</mn><mo>&#x2062;</mo><mi>x</mi><mo>+</mo><mn>1</mn>"
+        );
+        let polynomial: Polynomial = Polynomial::new("-X / 3 = 0").unwrap();
+        assert_eq!(
+            reduced_form_to_mathml(&polynomial),
+            "<mo>−</mo><mfrac><mn>1</mn><mn>3</mn></mfrac><mo>&#x2062;</mo><mi>x</mi>"
+        );
+    }
+
+    #[test]
+    fn test_formula() {
+        let root: Formula =
+            (-symbol("b") - symbol("∆").sqrt()) / product(vec![number(2), symbol("a")]);
+        assert_eq!(
+            formula_to_mathml(&indexed("x", 1).equals(root)),
+            "<mrow><msub><mi>x</mi><mn>1</mn></msub><mo>=</mo><mfrac>\
+             <mrow><mo>−</mo><mi>b</mi><mo>−</mo><msqrt><mi>∆</mi></msqrt></mrow>\
+             <mrow><mn>2</mn><mo>&#x2062;</mo><mi>a</mi></mrow></mfrac></mrow>"
+        );
+        let discriminant: Formula =
+            symbol("b").pow(2) - product(vec![number(4), symbol("a"), symbol("c")]);
+        assert_eq!(
+            formula_to_mathml(&symbol("∆").equals(discriminant)),
+            "<mrow><mi>∆</mi><mo>=</mo><msup><mrow><mi>b</mi></mrow><mn>2</mn></msup><mo>−</mo>\
+             <mn>4</mn><mo>&#x2062;</mo><mi>a</mi><mo>&#x2062;</mo><mi>c</mi></mrow>"
+        );
+        assert_eq!(
+            formula_to_mathml(&symbol("u").equals((-symbol("q") / number(2)).cbrt())),
+            "<mrow><mi>u</mi><mo>=</mo><mroot><mrow><mfrac><mrow><mo>−</mo><mi>q</mi></mrow>\
+             <mrow><mn>2</mn></mrow></mfrac></mrow><mn>3</mn></mroot></mrow>"
+        );
+        assert_eq!(
+            formula_to_mathml(
+                &(symbol("∆") * number(2))
+                    .pow(2)
+                    .relation(Relation::Less, number(0))
+            ),
+            "<mrow><msup><mrow><mrow><mo>(</mo><mi>∆</mi><mo>·</mo><mn>2</mn><mo>)</mo></mrow></mrow>\
+             <mn>2</mn></msup><mo>&lt;</mo><mn>0</mn></mrow>"
+        );
+    }
+
+    #[test]
+    fn test_solutions() {
+        let section: String = solved_section("2 * X^2 + 3 * X - 1 = 0");
+        assert!(section.starts_with("<section>\n<h2>2 * X^2 + 3 * X - 1 = 0</h2>"));
+        assert!(section.contains(
+            "<li><math><mrow><mi>∆</mi><mo>&gt;</mo><mn>0</mn></mrow></math> ⇒ Two real solutions</li>"
+        ));
+        assert!(section.contains(
+            "<msub><mi>x</mi><mn>2</mn></msub><mo>=</mo><mfrac><mrow><mo>−</mo><mn>3</mn>\
+             <mo>+</mo><msqrt><mn>17</mn></msqrt></mrow><mrow><mn>4</mn></mrow></mfrac>"
+        ));
+
+        let section: String = solved_section("X^2 + 4 = 0");
+        assert!(section.contains("<mo>=</mo><mn>2</mn><mo>&#x2062;</mo><mi>i</mi>"));
+        assert!(section.contains("<mo>=</mo><mo>−</mo><mn>2</mn><mo>&#x2062;</mo><mi>i</mi>"));
+
+        let section: String = solved_section("X^2 + X + 1 = 0");
+        assert!(section.contains(
+            "<mo>=</mo><mfrac><mrow><mo>−</mo><mn>1</mn><mo>+</mo><mi>i</mi><mo>&#x2062;</mo>\
+             <msqrt><mn>3</mn></msqrt></mrow><mrow><mn>2</mn></mrow></mfrac>"
+        ));
+
+        assert!(
+            solved_section("X^2 + 2 * X + 1 = 0").contains("<mtext>&#xa0;(multiplicity 2)</mtext>")
+        );
+        assert!(solved_section("1 = 2").contains("<p>No solution.</p>"));
+    }
+
+    #[test]
+    fn test_document() {
+        let mut polynomial: Polynomial = Polynomial::new("X - 2 = 0").unwrap();
+        polynomial.solve();
+        let html: String = polynomial_to_html("X & 2", &polynomial);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h2>X &amp; 2</h2>"));
+        assert!(html.trim_end().ends_with("</html>"));
+        assert!(!html.contains("<script"));
+    }
+
+    #[test]
+    fn test_error_section() {
+        let error = Polynomial::new("X^^2 = 1").unwrap_err();
+        let section: String = error_section("X^^2 = 1", &error);
+        assert!(section.contains("<p class=\"error\">Error: "));
+        assert!(section.contains("<code><mark>X^</mark>^2 = 1</code>"));
+    }
+}
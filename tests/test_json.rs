@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::display::display_json::{error_to_json, polynomial_to_json, Json};
+    use computorv1::math_tools::polynomial::Polynomial;
+
+    fn solved_json(equation: &str) -> String {
+        let mut polynomial: Polynomial = Polynomial::new(equation).unwrap();
+        polynomial.solve();
+        polynomial_to_json(equation, &polynomial).to_string()
+    }
+
+    #[test]
+    fn test_values() {
+        assert_eq!(Json::Null.to_string(), "null");
+        assert_eq!(Json::Bool(true).to_string(), "true");
+        assert_eq!(Json::from(2usize).to_string(), "2");
+        assert_eq!(Json::from(-0.25).to_string(), "-0.25");
+        assert_eq!(Json::from(f64::NAN).to_string(), "null");
+        assert_eq!(Json::from(None::<String>).to_string(), "null");
+        assert_eq!(
+            Json::from("say \"∆\"\\\n\t\u{1}").to_string(),
+            "\"say \\\"∆\\\"\\\\\\n\\t\\u0001\""
+        );
+        assert_eq!(
+            Json::Object(vec![
+                (
+                    "a".to_string(),
+                    Json::Array(vec![Json::from(1.5), Json::Null])
+                ),
+                ("b".to_string(), Json::Object(Vec::new())),
+            ])
+            .with_field("line", Json::from(3usize))
+            .to_string(),
+            "{\"line\":3,\"a\":[1.5,null],\"b\":{}}"
+        );
+    }
+
+    #[test]
+    fn test_polynomial_to_json() {
+        let json: String = solved_json("2 * X^2 - 3 * X + 1 = 0");
+        assert!(json.starts_with(
+            "{\"input\":\"2 * X^2 - 3 * X + 1 = 0\",\"coefficients\":[\"1\",\"-3\",\"2\"],\
+             \"degree\":2,\"discriminant\":\"1\",\"steps\":[\"Quadratic equation"
+        ));
+        assert!(json.ends_with(
+            "\"solutions\":{\"type\":\"finite\",\"roots\":[\
+             {\"real\":0.5,\"imaginary\":0,\"is_real\":true,\"multiplicity\":1,\"exact\":\"0.5\"},\
+             {\"real\":1,\"imaginary\":0,\"is_real\":true,\"multiplicity\":1,\"exact\":\"1\"}],\
+             \"reason\":null},\"error\":null}"
+        ));
+
+        let json: String = solved_json("X^2 + 1 = 0");
+        assert!(json.contains(
+            "{\"real\":0,\"imaginary\":1,\"is_real\":false,\"multiplicity\":1,\"exact\":\"i\"}"
+        ));
+        assert!(json.contains("\"exact\":\"-i\""));
+        let json: String = solved_json("X^2 - X + 1 = 0");
+        assert!(json.contains("\"exact\":\"(1 + i√3) / 2\""));
+        assert!(json.contains("\"exact\":\"(1 - i√3) / 2\""));
+        let json: String = solved_json("4 * X^2 + 1 = 0");
+        assert!(json.contains("\"exact\":\"i / 2\""));
+        let json: String = solved_json("3 * X^2 - 3 * X + 1 = 0");
+        assert!(json.contains("\"exact\":\"(3 + i√3) / 6\""));
+        let json: String = solved_json("3 * X^2 = 1");
+        assert!(json.contains("\"exact\":\"√3 / 3\""));
+        let json: String = solved_json("X^2 = 2");
+        assert!(json.contains("\"exact\":\"-√2\""));
+        let json: String = solved_json("(X - 1)^3");
+        assert!(json.contains("\"discriminant\":null"));
+        assert!(json.contains("\"multiplicity\":3"));
+    }
+
+    #[test]
+    fn test_solution_sets() {
+        assert!(solved_json("X = X")
+            .contains("\"solutions\":{\"type\":\"identity\",\"roots\":null,\"reason\":null}"));
+        assert!(solved_json("1 = 2")
+            .contains("\"solutions\":{\"type\":\"contradiction\",\"roots\":[],\"reason\":null}"));
+
+        let polynomial: Polynomial = Polynomial::new("X^2 = 1").unwrap();
+        assert!(polynomial_to_json("X^2 = 1", &polynomial)
+            .to_string()
+            .contains("\"steps\":[],\"solutions\":null,\"error\":null"));
+    }
+
+    #[test]
+    fn test_error_to_json() {
+        let error = Polynomial::new("X^2 = 2 * X^").err().unwrap();
+        assert_eq!(
+            error_to_json("X^2 = 2 * X^", &error).to_string(),
+            "{\"input\":\"X^2 = 2 * X^\",\"coefficients\":null,\"degree\":null,\
+             \"discriminant\":null,\"steps\":null,\"solutions\":null,\
+             \"error\":{\"message\":\"invalid power: X^\",\"start\":10,\"end\":12,\
+             \"hint\":\"the power is missing, e.g. X^2.\"}}"
+        );
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::display::display_latex::{
+        antiderivative_to_latex, derivative_to_latex, error_to_latex, polynomial_to_latex,
+        reduced_form_to_latex,
+    };
+    use computorv1::errors::ComputorError;
+    use computorv1::math_tools::polynomial::Polynomial;
+
+    fn solved_latex(equation: &str) -> String {
+        let mut polynomial: Polynomial = Polynomial::new(equation).unwrap();
+        polynomial.solve();
+        polynomial_to_latex(&polynomial)
+    }
+
+    #[test]
+    fn test_reduced_form() {
+        let cases: [(&str, &str); 5] = [
+            ("3 * X^2 - 2 * X + 1 = 0", "3x^{2} - 2x + 1"),
+            ("-X^3 + X = 0", "-x^{3} + x"),
+            ("X / 3 - 0.5 = 0", "\\frac{1}{3}x - 0.5"),
+            ("-2 * X^2 / 3 = 0", "-\\frac{2}{3}x^{2}"),
+            ("X = X", "0"),
+        ];
+        for (equation, expected) in cases {
+            let polynomial: Polynomial = Polynomial::new(equation).unwrap();
+            assert_eq!(reduced_form_to_latex(&polynomial), expected, "{}", equation);
+        }
+    }
+
+    #[test]
+    fn test_quadratic_steps() {
+        assert_eq!(
+            solved_latex("2 * X^2 + 3 * X - 1 = 0"),
+            "\\[ 2x^{2} + 3x - 1 = 0 \\]\n\
+             \\[ \\text{Quadratic equation with discriminant: } \\Delta = b^{2} - 4ac = 3^{2} - 4 \\cdot 2 \\cdot (-1) = 17 \\]\n\
+             \\[ \\Delta > 0 \\Rightarrow \\text{Two real solutions} \\]\n\
+             \\[ x_{1} = \\frac{-b - \\sqrt{\\Delta}}{2a} = \\frac{-3 - \\sqrt{\\Delta}}{2 \\cdot 2} = -1.7807764064044151 \\]\n\
+             \\[ x_{2} = \\frac{-b + \\sqrt{\\Delta}}{2a} = \\frac{-3 + \\sqrt{\\Delta}}{2 \\cdot 2} = 0.28077640640441515 \\]\n\
+             \\[ x_{1} = \\frac{-3 - \\sqrt{17}}{4}, \\quad x_{2} = \\frac{-3 + \\sqrt{17}}{4} \\]"
+        );
+        assert_eq!(
+            solved_latex("X^2 + 2 * X + 1 = 0"),
+            "\\[ x^{2} + 2x + 1 = 0 \\]\n\
+             \\[ \\text{Quadratic equation with discriminant: } \\Delta = b^{2} - 4ac = 2^{2} - 4 \\cdot 1 \\cdot 1 = 0 \\]\n\
+             \\[ \\Delta = 0 \\Rightarrow \\text{One single solution} \\]\n\
+             \\[ x_{0} = \\frac{-b}{2a} = \\frac{-2}{2 \\cdot 1} = -1 \\]\n\
+             \\[ x = -1 \\text{ (multiplicity 2)} \\]"
+        );
+    }
+
+    #[test]
+    fn test_steps_for_every_degree() {
+        let latex: String = solved_latex("X^3 - X + 1 = 0");
+        assert!(latex.contains(
+            "\\[ b = 0 \\Rightarrow \\text{Cubic equation already depressed: } X = t \\]"
+        ));
+        assert!(latex.contains("\\[ q = \\frac{2b^{3} - 9abc + 27a^{2}d}{27a^{3}} = 1 \\]"));
+        assert!(latex.contains(
+            "\\[ u = \\sqrt[3]{\\frac{-q}{2} \\pm \\sqrt{\\frac{q^{2}}{4} + \\frac{p^{3}}{27}}} = "
+        ));
+
+        let latex: String = solved_latex("X^4 + X + 1 = 0");
+        assert!(latex.contains(
+            "\\[ q \\neq 0 \\Rightarrow \\text{Ferrari's method with the resolvent cubic} \\]"
+        ));
+
+        let latex: String = solved_latex("X^5 - X + 1 = 0");
+        assert!(latex.contains("\\[ \\text{Converged after 6 iterations} \\]"));
+        assert!(latex.contains("\\text{ (error estimate: "));
+    }
+
+    #[test]
+    fn test_complex_solutions() {
+        let latex: String = solved_latex("3 * X^2 - 2 * X + 1 = 0");
+        assert!(latex.contains(
+            "\\frac{-b + i\\sqrt{-\\Delta}}{2a} = \\frac{2 + i\\sqrt{-\\Delta}}{2 \\cdot 3}"
+        ));
+        assert!(latex.ends_with(
+            "\\[ x_{1} = \\frac{1 + i\\sqrt{2}}{3}, \\quad x_{2} = \\frac{1 - i\\sqrt{2}}{3} \\]"
+        ));
+        assert!(solved_latex("X^2 + 4 = 0").ends_with("x_{1} = 2i, \\quad x_{2} = -2i \\]"));
+    }
+
+    #[test]
+    fn test_other_solution_sets() {
+        assert_eq!(
+            solved_latex("2 * X + 1 = 0"),
+            "\\[ 2x + 1 = 0 \\]\n\
+             \\[ \\text{Linear equation with one solution: } x = \\frac{-b}{a} = \\frac{-1}{2} = -0.5 \\]\n\
+             \\[ x = -0.5 \\]"
+        );
+        assert!(solved_latex("X = X").ends_with("\\text{Every real number is a solution.} \\]"));
+        assert!(solved_latex("1 = 2").ends_with("\\text{No solution.} \\]"));
+    }
+
+    #[test]
+    fn test_error() {
+        let error: ComputorError = Polynomial::new("X^3 - 6X^2 = 0").unwrap_err();
+        assert_eq!(
+            error_to_latex("X^3 - 6X^2 = 0", &error),
+            "\\[ \\text{Error: invalid coefficient: 6X\\^{}2.} \\]\n\
+             \\[ \\texttt{X\\^{}3 - }\\underline{\\texttt{6X\\^{}2}}\\texttt{ = 0} \\]\n\
+             \\[ \\text{Hint: did you mean 6 * X\\^{}2?} \\]"
+        );
+    }
+
+    #[test]
+    fn test_derivative_and_antiderivative() {
+        let polynomial: Polynomial = Polynomial::new("X^3 - X = 0").unwrap();
+        assert_eq!(
+            derivative_to_latex(&polynomial),
+            "\\[ \\frac{d}{dx}\\left(x^{3} - x\\right) = 3x^{2} - 1 \\]"
+        );
+        assert_eq!(
+            antiderivative_to_latex(&polynomial),
+            "\\[ \\int \\left(x^{3} - x\\right) dx = 0.25x^{4} - 0.5x^{2} + C \\]"
+        );
+    }
+}
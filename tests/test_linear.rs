@@ -1,34 +1,128 @@
 #[cfg(test)]
 mod tests {
+    use computorv1::math_tools::rational::Rational;
+    use computorv1::math_tools::root::Root;
+    use computorv1::math_tools::solution::{Solution, SolutionSet};
     use computorv1::solvers::linear::solve_linear;
 
+    fn rationals(values: &[&str]) -> Vec<Rational> {
+        values.iter().map(|value| value.parse().unwrap()).collect()
+    }
+
+    fn exact(numerator: i64, denominator: i64) -> Solution {
+        Solution::new(
+            Root::Exact(Rational::new(numerator.into(), denominator.into())),
+            1,
+        )
+    }
+
     #[test]
     fn test_simple_solution() {
-        assert_eq!(solve_linear(&[2.0, 4.0]), Some(vec![-0.5]));
+        assert_eq!(
+            solve_linear(&rationals(&["2", "4"])).solutions,
+            SolutionSet::Finite(vec![exact(-1, 2)])
+        );
     }
 
     #[test]
     fn test_negative_coefficient_a() {
-        assert_eq!(solve_linear(&[-3.0, 9.0]), Some(vec![0.3333333333333333]));
+        assert_eq!(
+            solve_linear(&rationals(&["-3", "9"])).solutions,
+            SolutionSet::Finite(vec![exact(1, 3)])
+        );
     }
 
     #[test]
     fn test_negative_coefficient_b() {
-        assert_eq!(solve_linear(&[4.0, -8.0]), Some(vec![0.5]));
+        assert_eq!(
+            solve_linear(&rationals(&["4", "-8"])).solutions,
+            SolutionSet::Finite(vec![exact(1, 2)])
+        );
     }
 
     #[test]
     fn test_no_solution() {
-        assert_eq!(solve_linear(&[0.0, 4.0]), Some(vec![0.0]));
+        assert_eq!(
+            solve_linear(&rationals(&["0", "4"])).solutions,
+            SolutionSet::Finite(vec![exact(0, 1)])
+        );
     }
 
     #[test]
     fn test_zero_both_coefficient() {
-        assert_eq!(solve_linear(&[0.0, 0.0]), None);
+        assert_eq!(
+            solve_linear(&rationals(&["0", "0"])).solutions,
+            SolutionSet::Identity
+        );
+        assert_eq!(
+            solve_linear(&rationals(&["0"])).solutions,
+            SolutionSet::Identity
+        );
+    }
+
+    #[test]
+    fn test_contradiction() {
+        assert_eq!(
+            solve_linear(&rationals(&["5", "0"])).solutions,
+            SolutionSet::Contradiction
+        );
+        assert_eq!(
+            solve_linear(&rationals(&["-2"])).solutions,
+            SolutionSet::Contradiction
+        );
     }
 
     #[test]
     fn test_edge_case_small_value() {
-        assert_eq!(solve_linear(&[1e-10, 1e-10]), Some(vec![-1.0]));
+        assert_eq!(
+            solve_linear(&rationals(&["0.0000000001", "0.0000000001"])).solutions,
+            SolutionSet::Finite(vec![exact(-1, 1)])
+        );
+    }
+
+    #[test]
+    fn test_exact_fraction() {
+        assert_eq!(
+            solve_linear(&rationals(&["2", "7"])).solutions,
+            SolutionSet::Finite(vec![exact(-2, 7)])
+        );
+        assert_eq!(
+            solve_linear(&rationals(&["0.1", "0.3"])).solutions,
+            SolutionSet::Finite(vec![exact(-1, 3)])
+        );
+    }
+
+    #[test]
+    fn test_derivation_steps() {
+        let steps: Vec<String> = solve_linear(&rationals(&["2", "4"]))
+            .steps
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            steps,
+            vec!["Linear equation with one solution: x = -b / a = -2 / 4 = -0.5"]
+        );
+
+        let sevenths: Vec<Rational> = vec![
+            Rational::new(2.into(), 7.into()),
+            Rational::new(1.into(), 7.into()),
+        ];
+        let steps: Vec<String> = solve_linear(&sevenths)
+            .steps
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            steps,
+            vec!["Linear equation with one solution: x = -b / a = (-2/7) / (1/7) = -2"]
+        );
+
+        let steps: Vec<String> = solve_linear(&rationals(&["0"]))
+            .steps
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(steps, vec!["0 = 0 => True for any value of X"]);
     }
 }
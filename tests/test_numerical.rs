@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::math_tools::complex::Complex;
+    use computorv1::math_tools::rational::Rational;
+    use computorv1::math_tools::root::Root;
+    use computorv1::math_tools::solution::{Solution, SolutionSet};
+    use computorv1::solvers::numerical::{find_roots, solve_numerical, NumericalRoots};
+
+    fn rationals(values: &[i64]) -> Vec<Rational> {
+        values.iter().map(|value| Rational::from(*value)).collect()
+    }
+
+    fn finite(solutions: SolutionSet) -> Vec<Solution> {
+        match solutions {
+            SolutionSet::Finite(solutions) => solutions,
+            other => panic!("Expected finite solutions, but got {:?}.", other),
+        }
+    }
+
+    fn contains_root(roots: &[Complex], expected: Complex, tolerance: f64) -> bool {
+        roots
+            .iter()
+            .any(|root| (*root - expected).modulus() < tolerance)
+    }
+
+    #[test]
+    fn test_find_roots() {
+        let polynomials: Vec<(Vec<f64>, Vec<Complex>)> = vec![
+            (
+                vec![-1.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+                (0..5)
+                    .map(|k| {
+                        let angle: f64 = 2.0 * std::f64::consts::PI * k as f64 / 5.0;
+                        Complex::new(angle.cos(), angle.sin())
+                    })
+                    .collect(),
+            ),
+            (
+                vec![
+                    -5040.0, 13068.0, -13132.0, 6769.0, -1960.0, 322.0, -28.0, 1.0,
+                ],
+                (1..=7).map(|x| Complex::from(x as f64)).collect(),
+            ),
+            (
+                vec![2.0, -3.0, 0.0, 1.0],
+                vec![Complex::from(-2.0), Complex::from(1.0)],
+            ),
+            (
+                vec![4.0, 0.0, 5.0, 0.0, 1.0],
+                vec![
+                    Complex::new(0.0, 1.0),
+                    Complex::new(0.0, -1.0),
+                    Complex::new(0.0, 2.0),
+                    Complex::new(0.0, -2.0),
+                ],
+            ),
+        ];
+
+        for (poly, expected) in polynomials {
+            let result: NumericalRoots = find_roots(&poly);
+
+            assert!(result.converged, "No convergence for {:?}.", poly);
+            assert_eq!(result.roots.len(), poly.len() - 1);
+            for (root, error) in expected.iter().zip(&result.errors) {
+                assert!(
+                    contains_root(&result.roots, *root, 1e-6),
+                    "Expected root {} for {:?}, but got {:?}.",
+                    root,
+                    poly,
+                    result.roots
+                );
+                assert!(error.is_finite());
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_estimates() {
+        let result: NumericalRoots = find_roots(&[-2.0, 0.0, 1.0]);
+
+        for (root, error) in result.roots.iter().zip(&result.errors) {
+            let distance: f64 = (root.real.abs() - 2f64.sqrt()).abs();
+            assert!(*error < 1e-14, "Error estimate too large: {}.", error);
+            assert!(
+                distance <= *error,
+                "{} is not within {} of √2.",
+                root,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn test_solve_numerical() {
+        let solutions: Vec<Solution> =
+            finite(solve_numerical(&rationals(&[0, 0, -6, 1, 1])).solutions);
+        assert_eq!(solutions.len(), 3);
+        assert_eq!(solutions[0], Solution::new(Root::Approximate(-3.0), 1));
+        assert_eq!(
+            solutions[1],
+            Solution::new(Root::Exact(Rational::zero()), 2)
+        );
+        assert!((solutions[2].root.to_f64() - 2.0).abs() < 1e-14);
+
+        let solutions: Vec<Solution> =
+            finite(solve_numerical(&rationals(&[1, 0, 1, 0, 0, 1])).solutions);
+        let real: Vec<&Solution> = solutions
+            .iter()
+            .filter(|solution| solution.root.is_real())
+            .collect();
+        assert_eq!(solutions.len(), 5);
+        assert_eq!(real.len(), 1);
+    }
+
+    #[test]
+    fn test_solve_numerical_repeated_roots() {
+        // (X - 1)^3 * (X + 2)^2 * (X^2 + 1)
+        let solutions: Vec<Solution> =
+            finite(solve_numerical(&rationals(&[-4, 8, -5, 3, 0, -4, 1, 1])).solutions);
+        assert_eq!(solutions.len(), 4);
+        assert_eq!(
+            solutions[0],
+            Solution::new(Root::Exact(Rational::from(-2)), 2)
+        );
+        assert_eq!(solutions[1], Solution::new(Root::Exact(Rational::one()), 3));
+        for (solution, imaginary) in solutions[2..].iter().zip([1.0, -1.0]) {
+            assert_eq!(solution.multiplicity, 1);
+            assert!((solution.root.to_complex() - Complex::new(0.0, imaginary)).modulus() < 1e-14);
+        }
+
+        // (X^2 - 2)^2, whose double roots are irrational
+        let solutions: Vec<Solution> =
+            finite(solve_numerical(&rationals(&[4, 0, -4, 0, 1])).solutions);
+        assert_eq!(solutions.len(), 2);
+        for (solution, expected) in solutions.iter().zip([-2f64.sqrt(), 2f64.sqrt()]) {
+            assert_eq!(solution.multiplicity, 2);
+            assert!((solution.root.to_f64() - expected).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Wrong solver used.")]
+    fn test_panic_on_constant() {
+        solve_numerical(&rationals(&[1]));
+    }
+}
@@ -1,6 +1,14 @@
 #[cfg(test)]
 mod tests {
+    use computorv1::errors::{ComputorError, Span};
+    use computorv1::math_tools::rational::Rational;
     use computorv1::parser;
+    use computorv1::parser::ParseMode;
+
+    fn parse_to_f64(input: &str, mode: ParseMode) -> Result<Vec<f64>, ComputorError> {
+        parser::parse_input_with_mode(input, mode)
+            .map(|coefficients| coefficients.iter().map(Rational::to_f64).collect())
+    }
 
     #[test]
     fn test_wrong_input() {
@@ -30,9 +38,260 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_error_kinds() {
+        let inputs = [
+            ("", ComputorError::EmptyInput),
+            ("=3", ComputorError::EmptyInput),
+            (
+                "3 * X^2 + 4h",
+                ComputorError::UnexpectedCharacter {
+                    character: 'h',
+                    span: Span::new(11, 12),
+                },
+            ),
+            (
+                "X = 2 = 3",
+                ComputorError::UnexpectedCharacter {
+                    character: '=',
+                    span: Span::new(6, 7),
+                },
+            ),
+            (
+                "3 * X^a",
+                ComputorError::UnexpectedCharacter {
+                    character: 'a',
+                    span: Span::new(6, 7),
+                },
+            ),
+            (
+                "3 * X ^ + 4",
+                ComputorError::InvalidPower {
+                    text: "X^".to_string(),
+                    span: Span::new(4, 7),
+                },
+            ),
+            (
+                "4*X^6.7",
+                ComputorError::InvalidPower {
+                    text: "X^6.7".to_string(),
+                    span: Span::new(2, 7),
+                },
+            ),
+            (
+                "X = -3X",
+                ComputorError::InvalidCoefficient {
+                    text: "3X".to_string(),
+                    span: Span::new(5, 7),
+                },
+            ),
+            (
+                "X^100000 = 0",
+                ComputorError::DegreeTooHigh {
+                    text: "X^100000".to_string(),
+                    span: Span::new(0, 8),
+                },
+            ),
+            (
+                "X^600 * X^600 = 1",
+                ComputorError::DegreeTooHigh {
+                    text: "X^600*X^600".to_string(),
+                    span: Span::new(0, 13),
+                },
+            ),
+            (
+                "(X^2 + 1)^501",
+                ComputorError::DegreeTooHigh {
+                    text: "(X^2+1)^501".to_string(),
+                    span: Span::new(0, 13),
+                },
+            ),
+        ];
+
+        for (input, expected) in inputs {
+            assert_eq!(
+                parser::parse_input(input),
+                Err(expected),
+                "Unexpected error for input '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_error_hints() {
+        let inputs = [
+            ("3X + 2 = 1", Some("did you mean 3 * X?")),
+            ("X2 + 1 = 0", Some("did you mean X^2?")),
+            (
+                "2 * X^2.0",
+                Some("powers must be non-negative integers, did you mean X^2?"),
+            ),
+            ("X^2 = 1,5", Some("use '.' as the decimal separator.")),
+            ("3 * * X", None),
+            (
+                "X^100000000000000000000 = 0",
+                Some("powers and degrees are limited to 1000."),
+            ),
+            (
+                "X^2000.5 = 0",
+                Some("powers and degrees are limited to 1000."),
+            ),
+            (
+                "X^1000.2 = 0",
+                Some("powers must be non-negative integers, did you mean X^1000?"),
+            ),
+            ("X2000 = 0", None),
+        ];
+
+        for (input, expected) in inputs {
+            let hint = parser::parse_input(input).unwrap_err().hint();
+            assert_eq!(hint.as_deref(), expected, "Unexpected hint for '{}'", input);
+        }
+    }
+
+    #[test]
+    fn test_power_input() {
+        let coefficients: Vec<Rational> = parser::parse_input("X^1000 = 1").unwrap();
+        assert_eq!(coefficients.len(), 1001);
+        assert_eq!(coefficients[0], Rational::from(-1));
+        assert_eq!(coefficients[1000], Rational::one());
+        assert!(coefficients[1..1000].iter().all(Rational::is_zero));
+
+        assert_eq!(
+            parse_to_f64("(2 * X)^3 + (X - 1)^5 = 0", ParseMode::Strict),
+            Ok(vec![-1.0, 5.0, -10.0, 18.0, -5.0, 1.0])
+        );
+        assert_eq!(
+            parse_to_f64("(X + 1)^0 + 3^2 = 0", ParseMode::Strict),
+            Ok(vec![10.0])
+        );
+    }
+
+    #[test]
+    fn test_parenthesised_input() {
+        let inputs = [
+            ("(X - 1) * (X + 2) = 3 * X", vec![-2.0, -2.0, 1.0]),
+            ("(X - 1)(X + 2)", vec![-2.0, 1.0, 1.0]),
+            ("2(X + 1)^2 = 0", vec![2.0, 4.0, 2.0]),
+            ("-(X - 3) = 1", vec![2.0, -1.0]),
+            ("-X^2", vec![0.0, 0.0, -1.0]),
+            ("(-X)^2", vec![0.0, 0.0, 1.0]),
+            ("((X))^3 - -2", vec![2.0, 0.0, 0.0, 1.0]),
+            ("3 * -X = (2)", vec![-2.0, -3.0]),
+            ("(X + 1)^0 = X * (1 - 1)", vec![1.0, 0.0]),
+            ("2 * 3 * X ^ 2 = 4^2", vec![-16.0, 0.0, 6.0]),
+        ];
+        run_parse_tests(&inputs);
+    }
+
+    #[test]
+    fn test_wrong_parenthesised_input() {
+        let inputs = [
+            "(X + 1",
+            "X + 1)",
+            "()",
+            "2 * (X - ) = 0",
+            "(X + 1)X",
+            "X^(2)",
+            "(X + 1)^-1",
+            "(X = 1)",
+        ];
+
+        for input in inputs {
+            let result = parser::parse_input(input);
+            assert!(
+                result.is_err(),
+                "Expected error for input '{}', but got: {:?}",
+                input,
+                result
+            );
+        }
+        assert_eq!(
+            parser::parse_input("3 * (X - 1"),
+            Err(ComputorError::UnclosedParenthesis {
+                span: Span::new(4, 5)
+            })
+        );
+        assert_eq!(
+            parser::parse_input("X * 2 +"),
+            Err(ComputorError::UnexpectedEnd {
+                span: Span::new(7, 7)
+            })
+        );
+    }
+
+    #[test]
+    fn test_division_input() {
+        let inputs = [
+            ("1/2 * X^2 = 0", vec![0.0, 0.0, 0.5]),
+            ("X/4 - 3/4", vec![-0.75, 0.25]),
+            ("(X + 1) / 2 = X / (4 - 2)", vec![0.5, 0.0]),
+            ("1 / 2 / 2 * X", vec![0.0, 0.25]),
+            ("X^2 / -0.5", vec![0.0, 0.0, -2.0]),
+        ];
+        run_parse_tests(&inputs);
+
+        assert_eq!(
+            parse_to_f64("1/2x + x²/4", ParseMode::Natural),
+            Ok(vec![0.0, 0.5, 0.25])
+        );
+    }
+
+    #[test]
+    fn test_wrong_division_input() {
+        let inputs = [
+            (
+                "3 / X = 1",
+                ComputorError::NonConstantDivisor {
+                    text: "X".to_string(),
+                    span: Span::new(4, 5),
+                },
+            ),
+            (
+                "X / (X - X) = 1",
+                ComputorError::DivisionByZero {
+                    text: "(X-X)".to_string(),
+                    span: Span::new(4, 11),
+                },
+            ),
+            (
+                "X = 1 / 0",
+                ComputorError::DivisionByZero {
+                    text: "0".to_string(),
+                    span: Span::new(8, 9),
+                },
+            ),
+        ];
+
+        for (input, expected) in inputs {
+            assert_eq!(parser::parse_input(input), Err(expected));
+        }
+        assert!(parser::parse_input("X / ").is_err());
+        assert!(parser::parse_input("/ X").is_err());
+    }
+
+    #[test]
+    fn test_exact_input() {
+        let third: Rational = Rational::new(1.into(), 3.into());
+        let inputs = [
+            ("X = 1/3", vec![-third.clone(), Rational::one()]),
+            ("0.1 + 0.2 = 0.3", vec![Rational::zero()]),
+            ("X/3 * 3 = 1", vec![-Rational::one(), Rational::one()]),
+            (
+                "3.000000000000000000001 = 3",
+                vec!["0.000000000000000000001".parse().unwrap()],
+            ),
+        ];
+
+        for (input, expected) in inputs {
+            assert_eq!(parser::parse_input(input), Ok(expected));
+        }
+    }
+
     fn run_parse_tests(inputs: &[(&str, Vec<f64>)]) {
         for (input, expected) in inputs {
-            match parser::parse_input(input) {
+            match parse_to_f64(input, ParseMode::Strict) {
                 Ok(result) => {
                     assert_eq!(
                         result, *expected,
@@ -92,4 +351,52 @@ mod tests {
         ];
         run_parse_tests(&inputs);
     }
+
+    #[test]
+    fn test_natural_input() {
+        let inputs = [
+            ("5 + 4x + x^2 = 0", vec![5.0, 4.0, 1.0]),
+            ("3X", vec![0.0, 3.0]),
+            ("2x² = 8", vec![-8.0, 0.0, 2.0]),
+            ("-X^2", vec![0.0, 0.0, -1.0]),
+            ("-x + 0.5x³", vec![0.0, -1.0, 0.0, 0.5]),
+            ("x¹⁰", vec![0.0; 10].into_iter().chain([1.0]).collect()),
+            ("3 * X^2 - 2*x = 4 * X^0", vec![-4.0, -2.0, 3.0]),
+            ("7 = 2.5x", vec![7.0, -2.5]),
+            ("xx - x²x", vec![0.0, 0.0, 1.0, -1.0]),
+            ("4²x", vec![0.0, 16.0]),
+            ("x(x - 1) = 2x", vec![0.0, -3.0, 1.0]),
+        ];
+
+        for (input, expected) in inputs {
+            assert_eq!(
+                parse_to_f64(input, ParseMode::Natural),
+                Ok(expected),
+                "Unexpected result for input '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_wrong_natural_input() {
+        let inputs = [
+            "3 + *x",
+            "x^",
+            "2x^2.5",
+            "3y",
+            "x ^ -2",
+            "x²²²²²²²²²²²²²²²²²²²²",
+        ];
+
+        for input in inputs {
+            let result = parser::parse_input_with_mode(input, ParseMode::Natural);
+            assert!(
+                result.is_err(),
+                "Expected error for input '{}', but got: {:?}",
+                input,
+                result
+            );
+        }
+    }
 }
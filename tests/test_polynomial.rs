@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::math_tools::polynomial::Polynomial;
+    use computorv1::math_tools::rational::Rational;
+
+    fn polynomial(values: &[i64]) -> Polynomial {
+        let coefficients: Vec<Rational> =
+            values.iter().map(|value| Rational::from(*value)).collect();
+        Polynomial::from_coefficients(&coefficients)
+    }
+
+    #[test]
+    fn test_from_coefficients() {
+        let p: Polynomial = polynomial(&[1, 2, 0, 0]);
+        assert_eq!(p.degree, 1);
+        assert_eq!(p.coefficients, vec![Rational::from(1), Rational::from(2)]);
+
+        assert!(polynomial(&[]).is_zero());
+        assert!(polynomial(&[0, 0]).is_zero());
+        assert_eq!(polynomial(&[0, 0]).coefficients, vec![Rational::zero()]);
+        assert_eq!(
+            polynomial(&[3, 0, 5]).leading_coefficient(),
+            &Rational::from(5)
+        );
+    }
+
+    #[test]
+    fn test_add_sub_neg() {
+        let p: Polynomial = polynomial(&[1, 2, 3]);
+        let q: Polynomial = polynomial(&[4, 5, -3]);
+
+        assert_eq!(&p + &q, polynomial(&[5, 7]));
+        assert_eq!(&p - &q, polynomial(&[-3, -3, 6]));
+        assert_eq!(-&p, polynomial(&[-1, -2, -3]));
+        assert!((&p - &p).is_zero());
+        assert_eq!((p.clone() - q.clone()).degree, 2);
+        assert_eq!(p + q, polynomial(&[5, 7]));
+    }
+
+    #[test]
+    fn test_mul() {
+        // (X - 1)(X + 1) = X^2 - 1
+        assert_eq!(
+            polynomial(&[-1, 1]) * polynomial(&[1, 1]),
+            polynomial(&[-1, 0, 1])
+        );
+        assert_eq!(
+            &polynomial(&[1, 2, 3]) * &Polynomial::zero(),
+            Polynomial::zero()
+        );
+        assert_eq!(
+            polynomial(&[1, -2]) * Rational::from(3),
+            polynomial(&[3, -6])
+        );
+        assert_eq!(
+            &polynomial(&[1, -2]) * &Rational::new(1.into(), 2.into()),
+            Polynomial::from_coefficients(&[Rational::new(1.into(), 2.into()), Rational::from(-1)])
+        );
+    }
+
+    #[test]
+    fn test_div_rem() {
+        // X^3 - 2X^2 - 4 = (X - 3)(X^2 + X + 3) + 5
+        let (quotient, remainder) = polynomial(&[-4, 0, -2, 1]).div_rem(&polynomial(&[-3, 1]));
+        assert_eq!(quotient, polynomial(&[3, 1, 1]));
+        assert_eq!(remainder, polynomial(&[5]));
+
+        let (quotient, remainder) = polynomial(&[-1, 0, 1]).div_rem(&polynomial(&[1, 1]));
+        assert_eq!(quotient, polynomial(&[-1, 1]));
+        assert!(remainder.is_zero());
+
+        let (quotient, remainder) = polynomial(&[1, 2]).div_rem(&polynomial(&[0, 0, 1]));
+        assert!(quotient.is_zero());
+        assert_eq!(remainder, polynomial(&[1, 2]));
+    }
+
+    #[test]
+    fn test_div_rem_identity() {
+        let dividend: Polynomial = polynomial(&[7, -3, 0, 5, 2, 1]);
+        let divisor: Polynomial = polynomial(&[1, 0, 3]);
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+
+        assert!(remainder.degree < divisor.degree);
+        assert_eq!(quotient * divisor + remainder, dividend);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Polynomial division by zero.")]
+    fn test_div_rem_by_zero() {
+        polynomial(&[1, 2]).div_rem(&Polynomial::zero());
+    }
+
+    #[test]
+    fn test_derivative() {
+        // d/dX (7 - X/2 + 3X^3) = -1/2 + 9X^2
+        let p: Polynomial = Polynomial::from_coefficients(&[
+            Rational::from(7),
+            Rational::new((-1).into(), 2.into()),
+            Rational::zero(),
+            Rational::from(3),
+        ]);
+        assert_eq!(
+            p.derivative(),
+            Polynomial::from_coefficients(&[
+                Rational::new((-1).into(), 2.into()),
+                Rational::zero(),
+                Rational::from(9),
+            ])
+        );
+        assert!(polynomial(&[5]).derivative().is_zero());
+        assert!(Polynomial::zero().derivative().is_zero());
+    }
+
+    #[test]
+    fn test_antiderivative() {
+        // ∫ (1 + X + 3X^2) dX = X + X^2/2 + X^3
+        let p: Polynomial = polynomial(&[1, 1, 3]);
+        assert_eq!(
+            p.antiderivative(),
+            Polynomial::from_coefficients(&[
+                Rational::zero(),
+                Rational::one(),
+                Rational::new(1.into(), 2.into()),
+                Rational::one(),
+            ])
+        );
+        assert_eq!(p.antiderivative().derivative(), p);
+        assert!(Polynomial::zero().antiderivative().is_zero());
+    }
+
+    #[test]
+    fn test_gcd() {
+        // gcd((X - 1)^2 (X + 2), 3(X - 1)(X + 5)) = X - 1
+        let p: Polynomial = polynomial(&[-1, 2, -1]) * polynomial(&[2, 1]);
+        let q: Polynomial = polynomial(&[-1, 1]) * polynomial(&[15, 3]);
+        assert_eq!(p.gcd(&q), polynomial(&[-1, 1]));
+        assert_eq!(q.gcd(&p), polynomial(&[-1, 1]));
+
+        assert_eq!(
+            polynomial(&[1, 1]).gcd(&polynomial(&[2, 1])),
+            polynomial(&[1])
+        );
+        assert_eq!(p.gcd(&Polynomial::zero()), p.monic());
+        assert!(Polynomial::zero().gcd(&Polynomial::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_root_bound() {
+        // X^3 - 3X + 1: 1 + max(3, 1)
+        assert_eq!(polynomial(&[1, -3, 0, 1]).root_bound(), Rational::from(4));
+        // 2X^2 - X - 3 = (X + 1)(2X - 3): 1 + 3/2
+        assert_eq!(
+            polynomial(&[-3, -1, 2]).root_bound(),
+            Rational::from(5) / Rational::from(2)
+        );
+        assert!(polynomial(&[7]).root_bound().is_zero());
+    }
+}
@@ -2,34 +2,62 @@
 
 #[cfg(test)]
 mod tests {
+    use computorv1::math_tools::complex::Complex;
+    use computorv1::math_tools::rational::Rational;
+    use computorv1::math_tools::root::Root;
+    use computorv1::math_tools::solution::{Solution, SolutionSet};
     use computorv1::solvers::quadratic::solve_quadratic;
 
+    fn rationals(values: &[&str]) -> Vec<Rational> {
+        values.iter().map(|value| value.parse().unwrap()).collect()
+    }
+
+    fn finite(solutions: SolutionSet) -> Option<Vec<Solution>> {
+        match solutions {
+            SolutionSet::Finite(solutions) => Some(solutions),
+            _ => None,
+        }
+    }
+
+    fn to_f64(solutions: Option<Vec<Solution>>) -> Option<Vec<f64>> {
+        solutions.map(|solutions| {
+            solutions
+                .iter()
+                .map(|solution| solution.root.to_f64())
+                .collect()
+        })
+    }
+
     #[test]
     fn test_no_real_solutions() {
-        let polynomials: Vec<Vec<f64>> = vec![
-            vec![1.0, 0.0, 1.0],
-            vec![4.0, 0.0, 2.0],
-            vec![5.0, 0.0, 3.0],
-            vec![2.0, 0.0, 1.0],
-            vec![9.0, 0.0, 4.0],
-            vec![5.0, 4.0, 1.0],
-            vec![7.0, -3.0, 2.0],
-            vec![1.0, 1.0, 1.0],
-            vec![8.0, -2.0, 5.0],
-            vec![4.0, 1.0, 3.0],
-            vec![10.0, 6.0, 1.0],
-            vec![9.0, 5.0, 4.0],
-            vec![2.0, -1.0, 1.0],
-            vec![3.0, 2.0, 6.0],
-            vec![3.0, 2.0, 1.0],
+        let polynomials: Vec<Vec<&str>> = vec![
+            vec!["1", "0", "1"],
+            vec!["4", "0", "2"],
+            vec!["5", "0", "3"],
+            vec!["2", "0", "1"],
+            vec!["9", "0", "4"],
+            vec!["5", "4", "1"],
+            vec!["7", "-3", "2"],
+            vec!["1", "1", "1"],
+            vec!["8", "-2", "5"],
+            vec!["4", "1", "3"],
+            vec!["10", "6", "1"],
+            vec!["9", "5", "4"],
+            vec!["2", "-1", "1"],
+            vec!["3", "2", "6"],
+            vec!["3", "2", "1"],
         ];
 
         for poly in polynomials.into_iter() {
-            let solutions: Option<Vec<f64>> = solve_quadratic(&poly);
+            let solutions: Option<Vec<Solution>> =
+                finite(solve_quadratic(&rationals(&poly)).solutions);
 
             if let Some(solutions) = solutions {
+                let values: Vec<Complex> = solutions.iter().map(Solution::value).collect();
                 assert_eq!(solutions.len(), 2);
-                assert!(solutions[1] > 0.0)
+                assert!(solutions.iter().all(|solution| !solution.root.is_real()));
+                assert!(values[0].imaginary > 0.0);
+                assert_eq!(values[1], values[0].conjugate());
             } else {
                 panic!("Expected complex solutions, but got None.");
             }
@@ -38,16 +66,16 @@ mod tests {
 
     #[test]
     fn test_one_solution() {
-        let polynomials: Vec<Vec<f64>> = vec![
-            vec![1.0, -2.0, 1.0],
-            vec![4.0, 4.0, 1.0],
-            vec![9.0, -6.0, 1.0],
-            vec![1.0, -4.0, 4.0],
-            vec![4.0, -12.0, 9.0],
-            vec![25.0, -10.0, 1.0],
-            vec![1.0, -8.0, 16.0],
-            vec![4.0, -20.0, 25.0],
-            vec![1.0, 12.0, 36.0],
+        let polynomials: Vec<Vec<&str>> = vec![
+            vec!["1", "-2", "1"],
+            vec!["4", "4", "1"],
+            vec!["9", "-6", "1"],
+            vec!["1", "-4", "4"],
+            vec!["4", "-12", "9"],
+            vec!["25", "-10", "1"],
+            vec!["1", "-8", "16"],
+            vec!["4", "-20", "25"],
+            vec!["1", "12", "36"],
         ];
 
         let expected: Vec<Vec<f64>> = vec![
@@ -62,8 +90,9 @@ mod tests {
             vec![-0.16666666666666666],
         ];
 
-        for (poly, exp) in polynomials.into_iter().zip(expected.into_iter()) {
-            let solutions: Option<Vec<f64>> = solve_quadratic(&poly);
+        for (poly, exp) in polynomials.into_iter().zip(expected) {
+            let solutions: Option<Vec<f64>> =
+                to_f64(finite(solve_quadratic(&rationals(&poly)).solutions));
 
             if let Some(sols) = solutions {
                 assert_eq!(sols.len(), exp.len(), "Expected one solution.");
@@ -83,16 +112,16 @@ mod tests {
 
     #[test]
     fn test_two_solutions() {
-        let polynomials: Vec<Vec<f64>> = vec![
-            vec![4.0, -5.0, 1.0],
-            vec![6.0, -5.0, 1.0],
-            vec![-4.0, 3.0, 1.0],
-            vec![3.0, -7.0, 2.0],
-            vec![-8.0, 2.0, 3.0],
-            vec![-10.0, -3.0, 1.0],
-            vec![5.0, 6.0, 1.0],
-            vec![-9.0, -4.0, 5.0],
-            vec![6.0, -11.0, 3.0],
+        let polynomials: Vec<Vec<&str>> = vec![
+            vec!["4", "-5", "1"],
+            vec!["6", "-5", "1"],
+            vec!["-4", "3", "1"],
+            vec!["3", "-7", "2"],
+            vec!["-8", "2", "3"],
+            vec!["-10", "-3", "1"],
+            vec!["5", "6", "1"],
+            vec!["-9", "-4", "5"],
+            vec!["6", "-11", "3"],
         ];
 
         let expected: Vec<Vec<f64>> = vec![
@@ -107,8 +136,9 @@ mod tests {
             vec![0.6666666666666666, 3.0],
         ];
 
-        for (poly, exp) in polynomials.into_iter().zip(expected.into_iter()) {
-            let solutions: Option<Vec<f64>> = solve_quadratic(&poly);
+        for (poly, exp) in polynomials.into_iter().zip(expected) {
+            let solutions: Option<Vec<f64>> =
+                to_f64(finite(solve_quadratic(&rationals(&poly)).solutions));
 
             if let Some(sols) = solutions {
                 assert_eq!(sols.len(), exp.len(), "Expected two real solutions.");
@@ -129,6 +159,67 @@ mod tests {
     #[test]
     #[should_panic(expected = "Wrong solver used.")]
     fn test_panic_on_zero_a() {
-        solve_quadratic(&[1.0, 2.0, 0.0]);
+        solve_quadratic(&rationals(&["1", "2", "0"]));
+    }
+
+    #[test]
+    fn test_exact_solutions() {
+        let half: Rational = Rational::new(1.into(), 2.into());
+        let solutions: SolutionSet = solve_quadratic(&rationals(&["3", "-7", "2"])).solutions;
+        assert_eq!(
+            solutions,
+            SolutionSet::Finite(vec![
+                Solution::new(Root::Exact(half), 1),
+                Solution::new(Root::Exact(Rational::from(3)), 1)
+            ])
+        );
+
+        let solutions: SolutionSet = solve_quadratic(&rationals(&["1", "-2", "1"])).solutions;
+        assert_eq!(
+            solutions,
+            SolutionSet::Finite(vec![Solution::new(Root::Exact(Rational::one()), 2)])
+        );
+
+        let solutions: SolutionSet = solve_quadratic(&rationals(&["1", "-1.5", "0.5"])).solutions;
+        assert_eq!(
+            solutions,
+            SolutionSet::Finite(vec![
+                Solution::new(Root::Exact(Rational::one()), 1),
+                Solution::new(Root::Exact(Rational::from(2)), 1)
+            ])
+        );
+
+        let solutions: Option<Vec<Root>> =
+            finite(solve_quadratic(&rationals(&["-1", "3", "2"])).solutions).map(|solutions| {
+                solutions
+                    .into_iter()
+                    .map(|solution| solution.root)
+                    .collect()
+            });
+        match solutions.as_deref() {
+            Some([Root::Surd(x1), Root::Surd(x2)]) => {
+                assert_eq!(x1.to_string(), "(-3 - √17) / 4");
+                assert_eq!(x2.to_string(), "(-3 + √17) / 4");
+                assert_eq!(x2.format_conjugates(), "(-3 ± √17) / 4");
+            }
+            other => panic!("Expected two surds, but got {:?}.", other),
+        }
+    }
+
+    #[test]
+    fn test_derivation_steps() {
+        let steps: Vec<String> = solve_quadratic(&rationals(&["1", "-2", "1"]))
+            .steps
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                "Quadratic equation with discriminant: ∆ = b^2 - 4ac = (-2)^2 - 4 * 1 * 1 = 0",
+                "∆ = 0 => One single solution",
+                "x0 = -b / (2a) = 2 / (2 * 1) = 1",
+            ]
+        );
     }
 }
@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::math_tools::complex::Complex;
+    use computorv1::math_tools::rational::Rational;
+    use computorv1::math_tools::root::Root;
+    use computorv1::math_tools::solution::{Solution, SolutionSet};
+    use computorv1::solvers::quartic::solve_quartic;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn rationals(values: &[i64]) -> Vec<Rational> {
+        values.iter().map(|value| Rational::from(*value)).collect()
+    }
+
+    fn roots(coefficients: &[i64]) -> Vec<Root> {
+        match solve_quartic(&rationals(coefficients)).solutions {
+            SolutionSet::Finite(solutions) => solutions
+                .into_iter()
+                .map(|solution| solution.root)
+                .collect(),
+            other => panic!("Expected finite solutions, but got {:?}.", other),
+        }
+    }
+
+    fn assert_close(solution: &Root, expected: Complex) {
+        let value: Complex = solution.to_complex();
+        assert!(
+            (value.real - expected.real).abs() < EPSILON
+                && (value.imaginary - expected.imaginary).abs() < EPSILON,
+            "Expected {}, but got {}.",
+            expected,
+            value
+        );
+    }
+
+    #[test]
+    fn test_biquadratic_solutions() {
+        let exact = |values: &[(i64, usize)]| -> Vec<Solution> {
+            values
+                .iter()
+                .map(|(value, multiplicity)| {
+                    Solution::new(Root::Exact(Rational::from(*value)), *multiplicity)
+                })
+                .collect()
+        };
+
+        assert_eq!(
+            solve_quartic(&rationals(&[4, 0, -5, 0, 1])).solutions,
+            SolutionSet::Finite(exact(&[(-2, 1), (-1, 1), (1, 1), (2, 1)]))
+        );
+        assert_eq!(
+            solve_quartic(&rationals(&[24, -50, 35, -10, 1])).solutions,
+            SolutionSet::Finite(exact(&[(1, 1), (2, 1), (3, 1), (4, 1)]))
+        );
+        assert_eq!(
+            solve_quartic(&rationals(&[1, 0, -2, 0, 1])).solutions,
+            SolutionSet::Finite(exact(&[(-1, 2), (1, 2)]))
+        );
+        assert_eq!(
+            solve_quartic(&rationals(&[1, -4, 6, -4, 1])).solutions,
+            SolutionSet::Finite(exact(&[(1, 4)]))
+        );
+
+        match roots(&[6, 0, -5, 0, 1]).as_slice() {
+            [Root::Surd(x1), Root::Surd(x2), Root::Surd(x3), Root::Surd(x4)] => {
+                let surds: Vec<String> = [x1, x2, x3, x4].iter().map(|x| x.to_string()).collect();
+                assert_eq!(surds, ["-√3", "-√2", "√2", "√3"]);
+            }
+            other => panic!("Expected four surds, but got {:?}.", other),
+        }
+    }
+
+    #[test]
+    fn test_complex_solutions() {
+        let half_sqrt_two: f64 = 2f64.sqrt() / 2.0;
+        let polynomials: Vec<(Vec<i64>, Vec<Complex>)> = vec![
+            (
+                vec![1, 0, 0, 0, 1],
+                vec![
+                    Complex::new(-half_sqrt_two, half_sqrt_two),
+                    Complex::new(-half_sqrt_two, -half_sqrt_two),
+                    Complex::new(half_sqrt_two, half_sqrt_two),
+                    Complex::new(half_sqrt_two, -half_sqrt_two),
+                ],
+            ),
+            (
+                vec![-6, -1, -5, -1, 1],
+                vec![
+                    Complex::new(-2.0, 0.0),
+                    Complex::new(3.0, 0.0),
+                    Complex::new(0.0, 1.0),
+                    Complex::new(0.0, -1.0),
+                ],
+            ),
+            (
+                vec![-3, 0, 0, 0, 1],
+                vec![
+                    Complex::new(-1.3160740129524924, 0.0),
+                    Complex::new(1.3160740129524924, 0.0),
+                    Complex::new(0.0, 1.3160740129524924),
+                    Complex::new(0.0, -1.3160740129524924),
+                ],
+            ),
+        ];
+
+        for (poly, expected) in polynomials {
+            let solutions: Vec<Root> = roots(&poly);
+
+            assert_eq!(
+                solutions.len(),
+                4,
+                "Expected four solutions for {:?}.",
+                poly
+            );
+            for (solution, expected) in solutions.iter().zip(expected) {
+                assert_close(solution, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ferrari_solutions() {
+        let polynomials: Vec<(Vec<i64>, Vec<Complex>)> = vec![
+            (
+                vec![30, -19, -15, 3, 1],
+                [-5.0, -2.0, 1.0, 3.0]
+                    .map(|x| Complex::new(x, 0.0))
+                    .to_vec(),
+            ),
+            (
+                vec![5, 2, 6, 2, 1],
+                vec![
+                    Complex::new(-1.0, 2.0),
+                    Complex::new(-1.0, -2.0),
+                    Complex::new(0.0, 1.0),
+                    Complex::new(0.0, -1.0),
+                ],
+            ),
+            (
+                vec![1, 1, 0, 0, 1],
+                vec![
+                    Complex::new(-0.7271360844911967, 0.43001428832971583),
+                    Complex::new(-0.7271360844911967, -0.43001428832971583),
+                    Complex::new(0.7271360844911967, 0.9340992894605294),
+                    Complex::new(0.7271360844911967, -0.9340992894605294),
+                ],
+            ),
+        ];
+
+        for (poly, expected) in polynomials {
+            let solutions: Vec<Root> = roots(&poly);
+
+            assert_eq!(
+                solutions.len(),
+                4,
+                "Expected four solutions for {:?}.",
+                poly
+            );
+            for (solution, expected) in solutions.iter().zip(expected) {
+                assert_close(solution, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_deflated_solutions() {
+        let third: Rational = Rational::new(1.into(), 3.into());
+        assert_eq!(
+            solve_quartic(&rationals(&[-14, 89, -155, 39, 9])).solutions,
+            SolutionSet::Finite(vec![
+                Solution::new(Root::Exact(Rational::from(-7)), 1),
+                Solution::new(Root::Exact(third), 2),
+                Solution::new(Root::Exact(Rational::from(2)), 1),
+            ])
+        );
+
+        let derivation = solve_quartic(&rationals(&[1, -2, 2, -2, 1]));
+        assert_eq!(
+            derivation.solutions,
+            SolutionSet::Finite(vec![
+                Solution::new(Root::Exact(Rational::one()), 2),
+                Solution::new(Root::Complex(Complex::new(0.0, 1.0)), 1),
+                Solution::new(Root::Complex(Complex::new(0.0, -1.0)), 1),
+            ])
+        );
+        let steps: Vec<String> = derivation.steps.iter().map(ToString::to_string).collect();
+        assert!(steps.contains(&"Rational root: X = 1 (multiplicity 2)".to_string()));
+        assert!(steps.contains(&"Quotient by the rational root factors: X^2 + 1 = 0".to_string()));
+
+        let solutions: Vec<Root> = roots(&[4, -2, 0, -2, 1]);
+        assert_eq!(solutions[1], Root::Exact(Rational::from(2)));
+        assert_close(&solutions[0], Complex::new(2f64.cbrt(), 0.0));
+    }
+
+    #[test]
+    fn test_substitution_step() {
+        let step = |coefficients: &[i64], index: usize| -> String {
+            solve_quartic(&rationals(coefficients)).steps[index].to_string()
+        };
+        assert_eq!(
+            step(&[1, 1, 0, 6, 1], 0),
+            "Substitution removing the cubic term: X = y - b / (4a) = y - 1.5"
+        );
+        assert_eq!(
+            step(&[4, 0, -5, 0, 1], 0),
+            "b = 0 => Quartic equation already depressed: X = y"
+        );
+        assert_eq!(
+            step(&[4, 0, -5, 0, 1], 8),
+            "X = ±√z - b / (4a) = -1, 1, -2, 2"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Wrong solver used.")]
+    fn test_panic_on_zero_a() {
+        solve_quartic(&rationals(&[1, 2, 3, 4, 0]));
+    }
+}
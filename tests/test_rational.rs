@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::math_tools::big_int::BigInt;
+    use computorv1::math_tools::rational::Rational;
+
+    fn big(value: i128) -> BigInt {
+        value.to_string().parse().unwrap()
+    }
+
+    fn fraction(numerator: i64, denominator: i64) -> Rational {
+        Rational::new(numerator.into(), denominator.into())
+    }
+
+    #[test]
+    fn test_big_int_arithmetic() {
+        let values: [i128; 9] = [
+            0,
+            1,
+            -1,
+            4_294_967_295,
+            4_294_967_296,
+            -98_765_432_109_876,
+            123_456_789_012_345_678_901,
+            -(1 << 100) + 12345,
+            (1 << 120) - 1,
+        ];
+
+        for a in values {
+            for b in values {
+                assert_eq!(big(a) + big(b), big(a + b), "{} + {}", a, b);
+                assert_eq!(big(a) - big(b), big(a - b), "{} - {}", a, b);
+                if let Some(product) = a.checked_mul(b) {
+                    assert_eq!(big(a) * big(b), big(product), "{} * {}", a, b);
+                }
+                if b != 0 {
+                    assert_eq!(big(a) / big(b), big(a / b), "{} / {}", a, b);
+                    assert_eq!(big(a) % big(b), big(a % b), "{} % {}", a, b);
+                }
+                assert_eq!(big(a).cmp(&big(b)), a.cmp(&b), "{} <=> {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_big_int_long_division() {
+        let mut seed: BigInt = "982451653".parse().unwrap();
+        let modulus: BigInt = BigInt::from(2).pow(521) - BigInt::one();
+
+        for _ in 0..50 {
+            seed = (&seed * &seed + BigInt::from(12345)) % &modulus;
+            let divisor: BigInt = seed.shr(seed.bits() / 3) + BigInt::one();
+            let quotient: BigInt = &seed * &BigInt::from(7919) + BigInt::from(3);
+            let remainder: BigInt = seed.shr(seed.bits() / 2) % &divisor;
+
+            let dividend: BigInt = &quotient * &divisor + &remainder;
+            assert_eq!(dividend.div_rem(&divisor), (quotient, remainder));
+        }
+    }
+
+    #[test]
+    fn test_big_int_conversions() {
+        let huge: &str = "-340282366920938463463374607431768211457123456789";
+        assert_eq!(huge.parse::<BigInt>().unwrap().to_string(), huge);
+        assert_eq!(BigInt::from(-42i64).to_i64(), Some(-42));
+        assert_eq!(BigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+        assert_eq!(big(1 << 70).to_i64(), None);
+        assert_eq!(big(1 << 70).to_f64(), 2f64.powi(70));
+        assert!("12a".parse::<BigInt>().is_err());
+        assert!("".parse::<BigInt>().is_err());
+    }
+
+    #[test]
+    fn test_big_int_gcd_lcm_and_sqrt() {
+        assert_eq!(big(462).gcd(&big(-1071)), big(21));
+        assert_eq!(big(0).gcd(&big(-5)), big(5));
+        assert_eq!(big(4).lcm(&big(6)), big(12));
+        assert_eq!(big(-4).lcm(&big(6)), big(-12));
+        assert_eq!(big(99).sqrt(), big(9));
+        assert_eq!(big(100).sqrt(), big(10));
+        assert_eq!(big(1 << 120).sqrt(), big(1 << 60));
+        assert_eq!(big((1 << 120) - 1).sqrt(), big((1 << 60) - 1));
+    }
+
+    #[test]
+    fn test_rational_normalisation() {
+        assert_eq!(fraction(2, 4), fraction(1, 2));
+        assert_eq!(fraction(3, -6), fraction(-1, 2));
+        assert_eq!(fraction(0, -7), Rational::zero());
+        assert_eq!(fraction(-4, 2).numerator(), &BigInt::from(-2));
+        assert_eq!(fraction(-4, 2).denominator(), &BigInt::one());
+    }
+
+    #[test]
+    fn test_rational_arithmetic() {
+        assert_eq!(fraction(1, 3) + fraction(1, 6), fraction(1, 2));
+        assert_eq!(fraction(1, 3) - fraction(1, 2), fraction(-1, 6));
+        assert_eq!(fraction(2, 3) * fraction(9, 4), fraction(3, 2));
+        assert_eq!(fraction(2, 3) / fraction(-4, 9), fraction(-3, 2));
+        assert_eq!(fraction(-2, 3).pow(3), fraction(-8, 27));
+        assert_eq!(fraction(9, 49).sqrt(), Some(fraction(3, 7)));
+        assert_eq!(fraction(2, 9).sqrt(), None);
+        assert!(fraction(1, 3) > fraction(-1, 2));
+        assert!(fraction(-1, 3) > fraction(-1, 2));
+    }
+
+    #[test]
+    fn test_rational_parsing_and_display() {
+        let inputs = [
+            ("3", fraction(3, 1), "3"),
+            ("-0.25", fraction(-1, 4), "-0.25"),
+            (".5", fraction(1, 2), "0.5"),
+            ("4.", fraction(4, 1), "4"),
+            ("3.4321", fraction(34321, 10000), "3.4321"),
+        ];
+
+        for (text, expected, displayed) in inputs {
+            let value: Rational = text.parse().unwrap();
+            assert_eq!(value, expected);
+            assert_eq!(value.to_string(), displayed);
+        }
+        assert_eq!(fraction(-2, 7).to_string(), "-2/7");
+        assert_eq!(fraction(1, 40).to_string(), "0.025");
+        assert!(".".parse::<Rational>().is_err());
+        assert!("1.2.3".parse::<Rational>().is_err());
+    }
+
+    #[test]
+    fn test_rational_to_f64() {
+        assert_eq!(fraction(1, 3).to_f64(), 1.0 / 3.0);
+        assert_eq!(fraction(-2, 7).to_f64(), -2.0 / 7.0);
+        assert_eq!(fraction(5, 1).to_f64(), 5.0);
+
+        let tiny: Rational = Rational::new(BigInt::one(), BigInt::from(10).pow(400));
+        assert_eq!(tiny.to_f64(), 0.0);
+        let ratio: Rational = Rational::new(BigInt::from(10).pow(400), BigInt::from(10).pow(399));
+        assert_eq!(ratio.to_f64(), 10.0);
+    }
+}
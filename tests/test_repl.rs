@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::math_tools::polynomial::Polynomial;
+    use computorv1::math_tools::solution::SolutionSet;
+    use computorv1::parser::ParseMode;
+    use computorv1::repl::command::{is_name, parse_command, Command};
+    use computorv1::repl::session::Session;
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(parse_command(":help"), Ok(Command::Help));
+        assert_eq!(parse_command(" :history "), Ok(Command::History));
+        assert_eq!(parse_command(":quit"), Ok(Command::Quit));
+        assert!(parse_command(":exit").is_err());
+
+        assert_eq!(
+            parse_command("p1 = X^2 - 1 = 0"),
+            Ok(Command::Bind {
+                name: "p1".to_string(),
+                equation: "X^2 - 1 = 0".to_string()
+            })
+        );
+        assert_eq!(
+            parse_command("derive p1"),
+            Ok(Command::Derive("p1".to_string()))
+        );
+        assert_eq!(
+            parse_command("integrate  X^2 + 1"),
+            Ok(Command::Integrate("X^2 + 1".to_string()))
+        );
+        assert_eq!(
+            parse_command("verify p1"),
+            Ok(Command::Verify("p1".to_string()))
+        );
+        assert_eq!(
+            parse_command("sturm p1"),
+            Ok(Command::Sturm("p1".to_string()))
+        );
+        assert_eq!(
+            parse_command("X = 2 * X^2"),
+            Ok(Command::Solve("X = 2 * X^2".to_string()))
+        );
+        assert_eq!(parse_command("p1"), Ok(Command::Solve("p1".to_string())));
+    }
+
+    #[test]
+    fn test_is_name() {
+        assert!(is_name("p1"));
+        assert!(is_name("my_equation"));
+        assert!(!is_name("X"));
+        assert!(!is_name("x2"));
+        assert!(!is_name("1p"));
+        assert!(!is_name("p 1"));
+        assert!(!is_name(""));
+    }
+
+    #[test]
+    fn test_session() {
+        let mut session: Session = Session::new(ParseMode::Strict);
+        assert!(session.execute("p1 = X^2 - 1 = 0"));
+        assert!(session.execute("derive p1"));
+        assert!(session.execute(""));
+        assert!(session.execute("derive p2"));
+        assert!(session.execute(":unknown"));
+        assert!(session.execute("X^2 = 2 * X^"));
+        assert!(session.execute(":history"));
+        assert_eq!(
+            session.history(),
+            &[
+                "p1 = X^2 - 1 = 0",
+                "derive p1",
+                "derive p2",
+                ":unknown",
+                "X^2 = 2 * X^",
+                ":history"
+            ]
+        );
+
+        let p1: &Polynomial = session.equation("p1").unwrap();
+        assert_eq!(p1.degree, 2);
+        assert!(matches!(p1.solutions, Some(SolutionSet::Finite(_))));
+        assert!(session.equation("p2").is_none());
+
+        assert!(session.execute("p1 = X = 3"));
+        assert_eq!(session.equation("p1").unwrap().degree, 1);
+        assert!(!session.execute(":quit"));
+    }
+}
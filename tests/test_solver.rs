@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::math_tools::polynomial::Polynomial;
+    use computorv1::math_tools::rational::Rational;
+    use computorv1::math_tools::root::Root;
+    use computorv1::math_tools::solution::{Solution, SolutionSet};
+    use computorv1::solvers::derivation::{Derivation, Step};
+    use computorv1::solvers::registry::SolverRegistry;
+    use computorv1::solvers::solver::{QuadraticSolver, Solver};
+
+    /// Answers every quadratic equation with X = 42.
+    struct ConstantSolver;
+
+    impl Solver for ConstantSolver {
+        fn name(&self) -> &str {
+            "constant"
+        }
+
+        fn can_solve(&self, coefficients: &[Rational]) -> bool {
+            coefficients.len() == 3
+        }
+
+        fn solve(&self, _coefficients: &[Rational]) -> Derivation {
+            Derivation::new(
+                vec![Step::note("Always 42")],
+                SolutionSet::Finite(vec![Solution::new(Root::Exact(Rational::from(42)), 1)]),
+            )
+        }
+    }
+
+    fn rationals(values: &[i64]) -> Vec<Rational> {
+        values.iter().map(|value| Rational::from(*value)).collect()
+    }
+
+    #[test]
+    fn test_builtin_dispatch() {
+        let registry: SolverRegistry = SolverRegistry::new();
+        let cases: Vec<(Vec<i64>, &str)> = vec![
+            (vec![0], "linear"),
+            (vec![1, 2], "linear"),
+            (vec![1, 2, 3], "quadratic"),
+            (vec![1, 2, 3, 4], "cubic"),
+            (vec![1, 2, 3, 4, 5], "quartic"),
+            (vec![1, 2, 3, 4, 5, 6], "numerical"),
+        ];
+
+        for (coefficients, name) in cases {
+            let solver: Option<&dyn Solver> = registry.find(&rationals(&coefficients));
+            assert_eq!(solver.map(|solver| solver.name()), Some(name));
+        }
+    }
+
+    #[test]
+    fn test_numerical_dispatch() {
+        let registry: SolverRegistry = SolverRegistry::numerical();
+        let name = |coefficients: &[i64]| registry.find(&rationals(coefficients)).unwrap().name();
+
+        assert_eq!(name(&[3]), "linear");
+        assert_eq!(name(&[1, 2]), "numerical");
+        assert_eq!(name(&[1, 2, 3]), "numerical");
+    }
+
+    #[test]
+    fn test_registered_solver_takes_precedence() {
+        let mut registry: SolverRegistry = SolverRegistry::new();
+        registry.register(Box::new(ConstantSolver));
+
+        let mut polynomial: Polynomial = Polynomial::new("X^2 - 1 = 0").unwrap();
+        polynomial.solve_with(&registry);
+        assert_eq!(polynomial.steps, vec![Step::note("Always 42")]);
+        assert_eq!(
+            polynomial.solutions,
+            Some(SolutionSet::Finite(vec![Solution::new(
+                Root::Exact(Rational::from(42)),
+                1
+            )]))
+        );
+
+        let mut polynomial: Polynomial = Polynomial::new("X - 1 = 0").unwrap();
+        polynomial.solve_with(&registry);
+        assert_eq!(
+            polynomial.solutions,
+            Some(SolutionSet::Finite(vec![Solution::new(
+                Root::Exact(Rational::one()),
+                1
+            )]))
+        );
+    }
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(
+            SolverRegistry::new().names(),
+            vec!["numerical", "linear", "quadratic", "cubic", "quartic"]
+        );
+        assert_eq!(
+            SolverRegistry::default().names(),
+            SolverRegistry::new().names()
+        );
+        assert!(SolverRegistry::empty().names().is_empty());
+    }
+
+    #[test]
+    fn test_no_solver() {
+        let mut registry: SolverRegistry = SolverRegistry::empty();
+        registry.register(Box::new(QuadraticSolver));
+        assert_eq!(registry.names(), vec!["quadratic"]);
+
+        let mut polynomial: Polynomial = Polynomial::new("X^3 = 1").unwrap();
+        polynomial.solve_with(&registry);
+        assert!(matches!(
+            polynomial.solutions,
+            Some(SolutionSet::Unsupported { .. })
+        ));
+    }
+}
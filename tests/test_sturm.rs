@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::math_tools::polynomial::Polynomial;
+    use computorv1::math_tools::rational::Rational;
+    use computorv1::math_tools::sturm::{sign_variations, sturm_sequence};
+
+    fn polynomial(equation: &str) -> Polynomial {
+        Polynomial::new(equation).unwrap()
+    }
+
+    fn rational(value: i64) -> Rational {
+        Rational::from(value)
+    }
+
+    #[test]
+    fn test_sturm_sequence() {
+        // X^3 - 3X + 1 has three real roots.
+        let sequence: Vec<Polynomial> = sturm_sequence(&polynomial("X^3 - 3*X + 1"));
+        assert_eq!(sequence.len(), 4);
+        assert_eq!(sequence[1], polynomial("3*X^2 - 3"));
+        assert_eq!(sequence[2], polynomial("2*X - 1"));
+        assert_eq!(sign_variations(&sequence, &rational(-3)), 3);
+        assert_eq!(sign_variations(&sequence, &rational(3)), 0);
+    }
+
+    #[test]
+    fn test_count_real_roots() {
+        let p: Polynomial = polynomial("X^3 - 6*X^2 + 11*X - 6");
+        assert_eq!(p.count_real_roots(&rational(-10), &rational(10)), 3);
+        assert_eq!(p.count_real_roots(&rational(0), &rational(2)), 2);
+        assert_eq!(p.count_real_roots(&rational(1), &rational(2)), 1);
+        assert_eq!(p.count_real_roots(&rational(3), &rational(10)), 0);
+        assert_eq!(p.count_real_roots(&rational(2), &rational(1)), 0);
+
+        // Repeated roots are counted once.
+        let p: Polynomial = polynomial("(X - 1)^3 * (X + 2)^2 * (X^2 + 1)");
+        assert_eq!(p.count_real_roots(&rational(-10), &rational(10)), 2);
+        assert_eq!(
+            polynomial("X^2 + 1").count_real_roots(&rational(-10), &rational(10)),
+            0
+        );
+    }
+
+    #[test]
+    fn test_isolate_real_roots() {
+        let p: Polynomial = polynomial("X^7 - 3 * X^2 + 1");
+        let intervals: Vec<(Rational, Rational)> = p.isolate_real_roots();
+        assert_eq!(intervals.len(), 3);
+        for (low, high) in &intervals {
+            assert!(low < high);
+            assert_eq!(p.count_real_roots(low, high), 1);
+            // Each simple root changes the sign of the polynomial inside its interval.
+            assert!(p.evaluate(low.to_f64()) * p.evaluate(high.to_f64()) <= 0.0);
+        }
+        for pair in intervals.windows(2) {
+            assert!(pair[0].1 <= pair[1].0);
+        }
+
+        assert!(polynomial("X^4 + 1").isolate_real_roots().is_empty());
+        assert!(polynomial("3").isolate_real_roots().is_empty());
+        assert_eq!(polynomial("(X - 2)^4").isolate_real_roots().len(), 1);
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::math_tools::big_int::BigInt;
+    use computorv1::math_tools::rational::Rational;
+    use computorv1::math_tools::surd::{square_free_decomposition, Surd};
+
+    fn fraction(numerator: i64, denominator: i64) -> Rational {
+        Rational::new(numerator.into(), denominator.into())
+    }
+
+    #[test]
+    fn test_square_free_decomposition() {
+        let inputs: [(i64, i64, i64); 6] = [
+            (17, 1, 17),
+            (8, 2, 2),
+            (72, 6, 2),
+            (49, 7, 1),
+            (1, 1, 1),
+            (4 * 10_007 * 10_007, 20_014, 1),
+        ];
+
+        for (value, square_root, radicand) in inputs {
+            assert_eq!(
+                square_free_decomposition(&BigInt::from(value)),
+                (BigInt::from(square_root), BigInt::from(radicand)),
+                "Unexpected decomposition of {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_surd_simplification() {
+        let inputs = [
+            ((0, 1), (1, 1), (8, 1), "2√2", 8f64.sqrt()),
+            (
+                (-3, 4),
+                (1, 4),
+                (17, 1),
+                "(-3 + √17) / 4",
+                0.28077640640441515,
+            ),
+            ((1, 2), (-1, 2), (5, 1), "(1 - √5) / 2", -0.6180339887498949),
+            ((0, 1), (1, 1), (50, 9), "5√2 / 3", 2.357022603955158),
+            ((2, 1), (-3, 1), (1, 3), "2 - √3", 2.0 - 3f64.sqrt()),
+        ];
+
+        for (rational, factor, value, expected, approximation) in inputs {
+            let surd: Surd = Surd::new(
+                &fraction(rational.0, rational.1),
+                &fraction(factor.0, factor.1),
+                &fraction(value.0, value.1),
+            )
+            .unwrap();
+            assert_eq!(surd.to_string(), expected);
+            assert!((surd.to_f64() - approximation).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_rational_square_root_is_not_a_surd() {
+        assert_eq!(
+            Surd::new(&Rational::one(), &Rational::one(), &fraction(9, 4)),
+            None
+        );
+        assert_eq!(
+            Surd::new(&Rational::one(), &Rational::one(), &fraction(-2, 1)),
+            None
+        );
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use computorv1::math_tools::complex::Complex;
+    use computorv1::math_tools::polynomial::Polynomial;
+    use computorv1::math_tools::rational::Rational;
+    use computorv1::math_tools::root::Root;
+    use computorv1::math_tools::verification::{verify_root, verify_solutions, Verification};
+
+    fn polynomial(values: &[i64]) -> Polynomial {
+        let coefficients: Vec<Rational> =
+            values.iter().map(|value| Rational::from(*value)).collect();
+        Polynomial::from_coefficients(&coefficients)
+    }
+
+    #[test]
+    fn test_evaluate() {
+        // 2X^3 - 6X^2 + 2X - 1
+        let p: Polynomial = polynomial(&[-1, 2, -6, 2]);
+        assert_eq!(p.evaluate(0.0), -1.0);
+        assert_eq!(p.evaluate(3.0), 5.0);
+        assert_eq!(p.evaluate(-1.0), -11.0);
+        assert_eq!(
+            p.evaluate_rational(&Rational::new(1.into(), 2.into())),
+            Rational::new((-5).into(), 4.into())
+        );
+    }
+
+    #[test]
+    fn test_evaluate_complex() {
+        // X^2 + 1 vanishes at ±i
+        let p: Polynomial = polynomial(&[1, 0, 1]);
+        assert_eq!(p.evaluate_complex(Complex::new(0.0, 1.0)), Complex::zero());
+        assert_eq!(
+            p.evaluate_complex(Complex::new(1.0, 1.0)),
+            Complex::new(1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn test_verify_solutions() {
+        let equations: Vec<&str> = vec![
+            "X^2 - 3 * X + 2 = 0",
+            "2 * X^2 + 3 * X - 1 = 0",
+            "X^2 + X + 1 = 0",
+            "X^3 - 2 * X + 2 = 0",
+            "X^4 + X + 1 = 0",
+            "X^7 - 3 * X^2 + 1 = 0",
+        ];
+
+        for equation in equations {
+            let mut p: Polynomial = Polynomial::new(equation).unwrap();
+            p.solve();
+            let verifications: Vec<Verification> = verify_solutions(&p);
+            assert!(!verifications.is_empty());
+            for verification in verifications {
+                assert!(verification.accepted, "{}: {:?}", equation, verification);
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_exact_and_wrong_roots() {
+        let p: Polynomial = polynomial(&[-2, 1, 1]);
+
+        let exact: Verification = verify_root(&p, &Root::Exact(Rational::from(-2)));
+        assert_eq!(exact.residual, 0.0);
+        assert!(exact.accepted);
+
+        let wrong: Verification = verify_root(&p, &Root::Approximate(1.001));
+        assert!(wrong.residual > 1e-3);
+        assert!(!wrong.accepted);
+    }
+
+    #[test]
+    fn test_verify_without_finite_solutions() {
+        let mut p: Polynomial = Polynomial::new("X = X").unwrap();
+        assert!(verify_solutions(&p).is_empty());
+        p.solve();
+        assert!(verify_solutions(&p).is_empty());
+    }
+}